@@ -0,0 +1,85 @@
+use serde::{
+    de::{self, Deserializer, SeqAccess, Visitor},
+    Deserialize, Serialize,
+};
+use std::fmt;
+
+type Result<E> = std::result::Result<Authors, E>;
+
+/// Authors of a mod, deserialized from either a single `mod.json` author
+/// string or an array of them.
+#[derive(Serialize, Debug, PartialEq)]
+pub struct Authors(Vec<String>);
+
+struct Vis;
+impl<'a> Visitor<'a> for Vis {
+    type Value = Authors;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("author string or array of author strings")
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<E> {
+        Ok(Authors(vec![value]))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<E> {
+        Ok(Authors(vec![value.to_string()]))
+    }
+
+    fn visit_seq<A: SeqAccess<'a>>(self, mut seq: A) -> Result<A::Error> {
+        let mut names = vec![];
+        while let Some(name) = seq.next_element()? {
+            names.push(name);
+        }
+        Ok(Authors(names))
+    }
+}
+
+impl<'a> Deserialize<'a> for Authors {
+    fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<D::Error> {
+        deserializer.deserialize_any(Vis)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct T {
+        author: Option<Authors>,
+    }
+
+    fn authors(x: &[&str]) -> Option<Authors> {
+        Some(Authors(x.iter().map(|x| x.to_string()).collect()))
+    }
+
+    #[test]
+    fn authors_string() {
+        let t: T = serde_json::from_str(r#"{"author":"[red]Someone"}"#).unwrap();
+        assert_eq!(t.author, authors(&["[red]Someone"]));
+    }
+
+    #[test]
+    fn authors_array() {
+        let t: T = serde_json::from_str(r#"{"author":["Someone","Other"]}"#).unwrap();
+        assert_eq!(t.author, authors(&["Someone", "Other"]));
+    }
+
+    #[test]
+    fn authors_missing() {
+        let t: T = serde_json::from_str("{}").unwrap();
+        assert_eq!(t.author, None);
+    }
+
+    #[test]
+    fn authors_roundtrip() {
+        let t: T = serde_json::from_str(r#"{"author":"Someone"}"#).unwrap();
+        let json = serde_json::to_string(&t.author).unwrap();
+        assert_eq!(json, r#"["Someone"]"#);
+        let back: Authors = serde_json::from_str(&json).unwrap();
+        assert_eq!(Some(back), t.author);
+    }
+}
@@ -1,56 +1,44 @@
 use backend::*;
+use clap::Clap;
+
+/// mindustry-mods scraper.
+#[derive(Clap)]
+struct Opts {
+    /// Number of mods fetched, a few by default, since each costs a request
+    /// of the 60 an hour allowed without a token.
+    #[clap(short, long, default_value = "3")]
+    limit: usize,
+
+    /// Pretty print the JSON output, rather than minifying it.
+    #[clap(long)]
+    pretty: bool,
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let Opts { limit, pretty } = Opts::parse();
     let dirs = ProjectDirs::from("", "Mindustry-Mods", "Mindustry-Mods-Backend")
         .expect("Project directories returned None.");
     tokio::fs::create_dir_all(dirs.config_dir()).await?;
 
+    // the environment variable takes precedence over the token file
     let token_path = dirs.config_dir().join("github-token");
-    let mut file = match tokio::fs::File::open(token_path).await {
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
-            println!("Github token file not found.");
-            return Ok(());
+    let github = match tokio::fs::File::open(token_path).await {
+        Ok(mut file) if std::env::var(request::GitHub::TOKEN_VAR).is_err() => {
+            let mut token = "token ".to_string();
+            file.read_to_string(&mut token).await?;
+            request::GitHub::new(Some(token.trim())).await?
         }
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+        _ => request::GitHub::from_env().await?,
+    };
 
-        other => other,
-    }?;
-
-    let mut token = "token ".to_string();
-    file.read_to_string(&mut token).await?;
-
-    let github = request::GitHub::new(&token).await?;
-
-    let mods_source: Vec<ModSource> = {
-        let data = github
-            .get_contents_decoded(Content {
-                repo: "Anuken/MindustryMods",
-                file: "mods.json",
-            })
-            .await?;
-        serde_json::from_str(&data)
-    }?;
-
-    let mods_meta: Vec<serde_hjson::Value> = github
-        .get_all_decoded(mods_source.iter().take(3).map(|m| Content {
-            repo: &m.repo,
-            file: "mod.json",
-        }))
-        .await
-        .iter()
-        .filter_map(|x| match x {
-            Ok(x) => Some(serde_hjson::from_str(&x).ok()?),
-            // throw away all invalid results for now
-            Err(_) => None,
-        })
-        .collect();
-
-    let x: Vec<ModInfo> = serde_json::from_value(Hjson(mods_meta.to_json()).into())?;
+    let x: Vec<ModInfo> = fetch_mods(&github, Some(limit)).await?;
 
     // let x: JValue = mods_meta.into();
     // let x: Vec<HashMap<String, JValue>> = serde_json::from_str(&x).unwrap();
 
-    println!("{:?}", x);
+    println!("{}", to_json(&x, pretty)?);
 
     // let mods_meta: Vec<Mod> = mods_source.iter();
 
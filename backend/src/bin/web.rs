@@ -36,6 +36,7 @@ async fn main() -> Result<()> {
     let root: PathBuf = PathBuf::from("/web/mindustry-mods");
     let root_data: PathBuf = root.join("data");
     let static_dir: PathBuf = root.join("www/static");
+    let pages_dir: PathBuf = root.join("www/m");
 
     let db = sled::open(root_data.join("db"))?;
 
@@ -60,7 +61,47 @@ async fn main() -> Result<()> {
             }
         }));
 
+    // json data with a pre-compressed copy, sent instead to browsers which
+    // accept it, which decompress it through the content-encoding, so it's
+    // still fetched as plain json.
+    let data_dir = static_dir.join("data");
+    let gzip_data = warp::path!("static" / "data" / String)
+        .and(warp::header::optional::<String>("accept-encoding"))
+        .and_then(move |name: String, accept: Option<String>| {
+            let plain = data_dir.join(&name);
+            let compressed = data_dir.join(format!("{}.gz", name));
+            async move {
+                if !name.ends_with(".json") {
+                    return Err(warp::reject::not_found());
+                }
+                // files without a compressed copy are left to `static_dir`
+                let bytes = tokio::fs::read(compressed)
+                    .await
+                    .map_err(|_| warp::reject::not_found())?;
+                if accept.as_deref().map_or(false, accepts_gzip) {
+                    return Ok((bytes, true));
+                }
+                match tokio::fs::read(plain).await {
+                    Ok(bytes) => Ok((bytes, false)),
+                    Err(_) => Err(warp::reject::not_found()),
+                }
+            }
+        })
+        .map(|(bytes, gzip): (Vec<u8>, bool)| {
+            let response = warp::http::Response::builder()
+                .header("content-type", "application/json")
+                .header("vary", "accept-encoding");
+            let response = if gzip {
+                response.header("content-encoding", "gzip")
+            } else {
+                response
+            };
+            response.body(bytes)
+        });
+
+    let sitemap = warp::path("sitemap.xml").and(warp::fs::file(static_dir.join("sitemap.xml")));
     let static_dir = warp::path("static").and(warp::fs::dir(static_dir));
+    let pages = warp::path("m").and(warp::fs::dir(pages_dir));
 
     let stats = db.open_tree(b"statistics")?;
     let statistics = warp::path("statistics").map(move || {
@@ -83,7 +124,15 @@ async fn main() -> Result<()> {
 
     let version = warp::path("version").map(|| VERSION);
 
-    warp::serve(static_dir.or(statistics).or(version).or(index_file))
+    warp::serve(
+        gzip_data
+            .or(static_dir)
+            .or(sitemap)
+            .or(pages)
+            .or(statistics)
+            .or(version)
+            .or(index_file),
+    )
         .run(([0, 0, 0, 0], port))
         .await;
 
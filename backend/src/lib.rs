@@ -1,7 +1,10 @@
+pub mod authors;
 pub mod rate;
+pub mod repo;
 pub mod request;
 pub mod version;
 
+use crate::authors::Authors;
 pub use crate::request::Content;
 use crate::version::Version;
 
@@ -43,7 +46,7 @@ pub struct ModSource {
 pub struct ModInfo {
     name: Option<String>,
     description: Option<String>,
-    author: Option<String>,
+    author: Option<Authors>,
     version: Version,
     dependencies: Option<Vec<String>>,
     display_name: Option<String>,
@@ -83,10 +86,92 @@ pub struct Cache {
     sha: String,
     mod_info: ModInfo,
     readme: String,
+    #[serde(default)]
+    readme_unavailable: bool,
+    #[serde(default)]
+    changelog: Option<String>,
     assets: Vec<Assets>,
     contents: Vec<Contents>,
 }
 
+/// Serializes to JSON, minified by default for smaller downloads, or pretty
+/// printed for reading while debugging.
+pub fn to_json<T: Serialize>(value: &T, pretty: bool) -> Result<String> {
+    Ok(if pretty {
+        serde_json::to_string_pretty(value)?
+    } else {
+        serde_json::to_string(value)?
+    })
+}
+
+/// Whether an `Accept-Encoding` header accepts gzip, as in `"gzip, br"`,
+/// unless its quality is 0, as in `"gzip;q=0"`, which refuses it.
+pub fn accepts_gzip(accept_encoding: &str) -> bool {
+    accept_encoding.split(',').any(|coding| {
+        let mut params = coding.split(';').map(str::trim);
+        let name = params.next().unwrap_or("");
+        let refused = params.any(|param| {
+            param
+                .strip_prefix("q=")
+                .and_then(|q| q.parse::<f32>().ok())
+                .map_or(false, |q| q == 0.0)
+        });
+        (name.eq_ignore_ascii_case("gzip") || name == "*") && !refused
+    })
+}
+
+/// Fetches the mods listed at `Anuken/MindustryMods`, and then the
+/// `mod.json` of only the first `limit` of them when there's one, which makes
+/// for a faster edit-test loop against realistic data.
+pub async fn fetch_mods(github: &request::GitHub, limit: Option<usize>) -> Result<Vec<ModInfo>> {
+    let mods_source: Vec<ModSource> = {
+        let data = github
+            .get_contents_decoded(Content {
+                repo: "Anuken/MindustryMods",
+                file: "mods.json",
+            })
+            .await?;
+        serde_json::from_str::<Vec<ModSource>>(&data)
+    }?
+    .into_iter()
+    .filter_map(|mut m| match repo::normalize(&m.repo) {
+        Ok(repo) => {
+            m.repo = repo;
+            Some(m)
+        }
+        Err(e) => {
+            println!("[error] {}", e);
+            None
+        }
+    })
+    .take(limit.unwrap_or(usize::MAX))
+    .collect();
+
+    let mods_meta: Vec<serde_hjson::Value> = github
+        .get_all_decoded(mods_source.iter().map(|m| Content {
+            repo: &m.repo,
+            file: "mod.json",
+        }))
+        .await
+        .iter()
+        .zip(&mods_source)
+        .filter_map(|(x, m)| match x {
+            Ok(x) => Some(serde_hjson::from_str(&x).ok()?),
+            Err(e) if request::GitHubError::is_not_found(e) => {
+                println!("[log] removed -- {}, not found", m.repo);
+                None
+            }
+            // throw away all invalid results for now
+            Err(e) => {
+                println!("[error] {} -- {}", m.repo, e);
+                None
+            }
+        })
+        .collect();
+
+    Ok(serde_json::from_value(Hjson(mods_meta.to_json()).into())?)
+}
+
 /// Type to allow conversion of Hjson and Json value.
 /// This is required because serde_hjson uses an older
 /// version of serde. (serde 0.7)
@@ -119,3 +204,19 @@ impl From<Hjson> for serde_json::Value {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn gzip_encoding() {
+        assert!(accepts_gzip("gzip"));
+        assert!(accepts_gzip("gzip, deflate, br"));
+        assert!(accepts_gzip("br;q=1.0, GZIP;q=0.5"));
+        assert!(accepts_gzip("*"));
+        assert!(!accepts_gzip("gzip;q=0"));
+        assert!(!accepts_gzip("deflate, br"));
+        assert!(!accepts_gzip(""));
+    }
+}
@@ -1,6 +1,6 @@
 //! GitHub rate limiting: https://developer.github.com/v3/rate_limit/
 
-use chrono::{serde::ts_seconds, DateTime, TimeZone, Utc};
+use chrono::{DateTime, TimeZone, Utc};
 use reqwest::header::HeaderMap;
 use serde::Deserialize;
 use std::sync::atomic::{AtomicI64, Ordering};
@@ -30,13 +30,15 @@ pub struct RateLimit {
     pub resources: Resources,
 }
 
+/// Rate limit of a resource, which is updated from the headers of every
+/// response, since the limit depends on whether requests are authenticated.
 #[derive(Deserialize, Debug)]
 pub struct Rate {
-    pub limit: i64,
+    pub limit: AtomicI64,
     pub remaining: AtomicI64,
 
-    #[serde(with = "ts_seconds")]
-    pub reset: DateTime<Utc>,
+    /// UTC timestamp epoch in seconds of when the limit resets.
+    pub reset: AtomicI64,
 }
 
 pub enum RateLimited {
@@ -53,9 +55,15 @@ impl Rate {
     /// If the time remaining is negative, this function waits zero seconds.
     pub async fn tick(&self) -> RateLimited {
         let now = Utc::now();
-        // leaves ourselves 500 requests if limit is over 1000
-        if self.limit > 1000 && self.remaining.load(Ordering::SeqCst) < 500 {
-            match (self.reset - now).to_std() {
+        // leaves ourselves 500 requests if limit is over 1000, as it is when
+        // authenticated, and otherwise uses every request
+        let reserve = if self.limit.load(Ordering::SeqCst) > 1000 {
+            500
+        } else {
+            0
+        };
+        if self.remaining.load(Ordering::SeqCst) <= reserve {
+            match (self.reset() - now).to_std() {
                 Ok(duration) => {
                     let later = tokio::time::Instant::now() + duration;
                     tokio::time::delay_until(later).await;
@@ -67,12 +75,27 @@ impl Rate {
                 Err(_) => RateLimited::Waited,
             }
         } else {
-            self.remaining
-                .store(self.remaining.load(Ordering::SeqCst) - 1, Ordering::SeqCst);
+            self.remaining.fetch_sub(1, Ordering::SeqCst);
             RateLimited::Decremented
         }
     }
 
+    /// Datetime of when the limit resets.
+    pub fn reset(&self) -> DateTime<Utc> {
+        Utc.timestamp(self.reset.load(Ordering::SeqCst), 0)
+    }
+
+    /// Replaces the counted limits by the ones of the `X-RateLimit-*` headers
+    /// of a response, which are authoritative.
+    pub fn update(&self, h: &HeaderMap) -> Result<(), RateLimitError> {
+        let rate = Self::from_headers(h)?;
+        self.limit.store(rate.limit.into_inner(), Ordering::SeqCst);
+        self.remaining
+            .store(rate.remaining.into_inner(), Ordering::SeqCst);
+        self.reset.store(rate.reset.into_inner(), Ordering::SeqCst);
+        Ok(())
+    }
+
     /// Reads `X-RateLimit-*` headers and packs them into a `Rate` struct.
     pub fn from_headers(h: &HeaderMap) -> Result<Self, RateLimitError> {
         fn get_parse(h: &HeaderMap, key: &'static str) -> Result<i64, RateLimitError> {
@@ -82,10 +105,9 @@ impl Rate {
                 .to_str()?
                 .parse::<i64>()?)
         }
-        let limit = get_parse(h, Self::X_RATELIMIT_LIMIT)?;
+        let limit = AtomicI64::new(get_parse(h, Self::X_RATELIMIT_LIMIT)?);
         let remaining = AtomicI64::new(get_parse(h, Self::X_RATELIMIT_REMAINING)?);
-        let ts = get_parse(h, Self::X_RATELIMIT_RESET)?;
-        let reset = Utc.timestamp(ts, 0);
+        let reset = AtomicI64::new(get_parse(h, Self::X_RATELIMIT_RESET)?);
         Ok(Self {
             limit,
             remaining,
@@ -93,3 +115,39 @@ impl Rate {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(limit: &'static str, remaining: &'static str) -> HeaderMap {
+        let mut h = HeaderMap::new();
+        h.insert(Rate::X_RATELIMIT_LIMIT, HeaderValue::from_static(limit));
+        h.insert(
+            Rate::X_RATELIMIT_REMAINING,
+            HeaderValue::from_static(remaining),
+        );
+        h.insert(
+            Rate::X_RATELIMIT_RESET,
+            HeaderValue::from_static("1600000000"),
+        );
+        h
+    }
+
+    #[test]
+    fn updated_from_headers() {
+        let rate = Rate::from_headers(&headers("60", "59")).unwrap();
+        rate.update(&headers("5000", "4999")).unwrap();
+        assert_eq!(rate.limit.load(Ordering::SeqCst), 5000);
+        assert_eq!(rate.remaining.load(Ordering::SeqCst), 4999);
+        assert_eq!(rate.reset(), Utc.timestamp(1_600_000_000, 0));
+    }
+
+    #[test]
+    fn missing_header() {
+        let rate = Rate::from_headers(&headers("60", "59")).unwrap();
+        assert!(rate.update(&HeaderMap::new()).is_err());
+        assert_eq!(rate.limit.load(Ordering::SeqCst), 60);
+    }
+}
@@ -0,0 +1,80 @@
+//! Repository strings, as found in the mod listing.
+//!
+//! They're expected to be `"owner/name"`, but some entries are full
+//! urls, or have leftover slashes and `.git` suffixes.
+
+use thiserror::Error;
+
+/// Repository string validation error.
+#[derive(Error, Debug, PartialEq)]
+pub enum RepoError {
+    #[error("repository is not of the form owner/name: {0:?}")]
+    Invalid(String),
+}
+
+const PREFIXES: &[&str] = &[
+    "https://github.com/",
+    "http://github.com/",
+    "https://www.github.com/",
+    "github.com/",
+];
+
+fn is_valid_part(part: &str) -> bool {
+    !part.is_empty()
+        && part != "."
+        && part != ".."
+        && part
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+}
+
+/// Normalizes a repository string into `"owner/name"`.
+pub fn normalize(input: &str) -> Result<String, RepoError> {
+    let mut repo = input.trim();
+    for prefix in PREFIXES {
+        if repo.starts_with(prefix) {
+            repo = &repo[prefix.len()..];
+            break;
+        }
+    }
+    let repo = repo.trim_matches('/');
+    let repo = repo.strip_suffix(".git").unwrap_or(repo);
+
+    let mut parts = repo.split('/');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(owner), Some(name), None) if is_valid_part(owner) && is_valid_part(name) => {
+            Ok(format!("{}/{}", owner, name))
+        }
+        _ => Err(RepoError::Invalid(input.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unchanged() {
+        let repo = "What42Pizza/Mindustry-Production-Mod";
+        assert_eq!(normalize(repo), Ok(repo.to_string()));
+    }
+
+    #[test]
+    fn cleaned() {
+        let repo = Ok("Anuken/ExampleMod".to_string());
+        assert_eq!(normalize(" Anuken/ExampleMod "), repo);
+        assert_eq!(normalize("Anuken/ExampleMod/"), repo);
+        assert_eq!(normalize("Anuken/ExampleMod.git"), repo);
+        assert_eq!(normalize("https://github.com/Anuken/ExampleMod"), repo);
+        assert_eq!(normalize("github.com/Anuken/ExampleMod.git"), repo);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(normalize("").is_err());
+        assert!(normalize("Anuken").is_err());
+        assert!(normalize("Anuken/ExampleMod/tree").is_err());
+        assert!(normalize("Anuken/../etc").is_err());
+        assert!(normalize("Anuken/Example Mod").is_err());
+    }
+}
@@ -3,9 +3,9 @@ use anyhow::Result;
 use futures::future::join_all;
 use reqwest::{
     header::{HeaderMap, HeaderValue, AUTHORIZATION, USER_AGENT},
-    Client,
+    Client, StatusCode,
 };
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
 use thiserror::Error;
 
 #[derive(Deserialize, Debug)]
@@ -22,8 +22,20 @@ pub struct Contents {
 
 #[derive(Error, Debug)]
 pub enum GitHubError {
-    #[error("file not found")]
-    Http404,
+    /// The repository or file doesn't exist, as when a repository was
+    /// deleted or made private.
+    #[error("not found: {0}")]
+    Http404(String),
+    #[error("unexpected status {status}: {url}")]
+    Http { status: StatusCode, url: String },
+}
+
+impl GitHubError {
+    /// Whether a request failed with a 404, as opposed to transient errors,
+    /// such that the repository can be dropped from the listing.
+    pub fn is_not_found(err: &anyhow::Error) -> bool {
+        matches!(err.downcast_ref(), Some(GitHubError::Http404(_)))
+    }
 }
 
 pub struct GitHub {
@@ -39,9 +51,17 @@ pub struct Content<'a> {
 impl GitHub {
     const RATE_LIMIT: &'static str = "https://api.github.com/rate_limit";
 
-    pub async fn new(token: &str) -> Result<Self> {
+    /// Environment variable of the GitHub token.
+    pub const TOKEN_VAR: &'static str = "GITHUB_TOKEN";
+
+    /// Client authenticated with a token, as in `"token {token}"`, which
+    /// raises the rate limit from 60 to 5000 requests an hour, or
+    /// unauthenticated if there's no token.
+    pub async fn new(token: Option<&str>) -> Result<Self> {
         let mut headers = HeaderMap::new();
-        headers.insert(AUTHORIZATION, HeaderValue::from_str(&token)?);
+        if let Some(token) = token {
+            headers.insert(AUTHORIZATION, HeaderValue::from_str(token)?);
+        }
         headers.insert(USER_AGENT, HeaderValue::from_str("Mindustry-Mods-Backend")?);
 
         let client = reqwest::Client::builder()
@@ -53,21 +73,45 @@ impl GitHub {
         Ok(Self { client, rate_limit })
     }
 
-    /// Get contents, and count requests with an atomic counter, delaying whenever
-    /// rate limit remaining is too small.
-    pub async fn get_contents(&self, Content { repo, file }: Content<'_>) -> Result<Contents> {
-        let url = format!("https://api.github.com/repos/{}/contents/{}", repo, file);
-        dbg!(&self.rate_limit.resources.core);
+    /// Client authenticated with the token of the `GITHUB_TOKEN` environment
+    /// variable, or unauthenticated if it's not set.
+    pub async fn from_env() -> Result<Self> {
+        match std::env::var(Self::TOKEN_VAR) {
+            Ok(token) if !token.trim().is_empty() => {
+                Self::new(Some(&format!("token {}", token.trim()))).await
+            }
+            _ => {
+                println!("[warn] no github token, requests are limited to 60 an hour");
+                Self::new(None).await
+            }
+        }
+    }
+
+    /// Get json from the api, and count requests with an atomic counter, delaying
+    /// whenever rate limit remaining is too small.
+    async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
         self.rate_limit.resources.core.tick().await;
-        let resp = self.client.get(&url).send().await?;
-        // self.rate_limit.resources.core = Rate::from_headers(resp.headers())?;
-        if resp.status() == 200 {
-            Ok(resp.json::<Contents>().await?)
-        } else {
-            Err(GitHubError::Http404.into())
+        let resp = self.client.get(url).send().await?;
+        if let Err(e) = self.rate_limit.resources.core.update(resp.headers()) {
+            println!("[warn] rate limit headers -- {}", e);
+        }
+        match resp.status() {
+            StatusCode::OK => Ok(resp.json::<T>().await?),
+            StatusCode::NOT_FOUND => Err(GitHubError::Http404(url.to_string()).into()),
+            status => Err(GitHubError::Http {
+                status,
+                url: url.to_string(),
+            }
+            .into()),
         }
     }
 
+    /// Get contents of a file.
+    pub async fn get_contents(&self, Content { repo, file }: Content<'_>) -> Result<Contents> {
+        let url = format!("https://api.github.com/repos/{}/contents/{}", repo, file);
+        self.get_json(&url).await
+    }
+
     /// Get base64 decoded contents.
     pub async fn get_contents_decoded(&self, content: Content<'_>) -> Result<String> {
         let resp = self.get_contents(content).await?;
@@ -92,3 +136,21 @@ impl GitHub {
         // join_all(repos.iter().map(|(r, f)| self.get_contents(r, f)).collect()).await
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn not_found() {
+        let url = "https://api.github.com/repos/deleted/mod".to_string();
+        assert!(GitHubError::is_not_found(
+            &GitHubError::Http404(url.clone()).into()
+        ));
+        let status = StatusCode::INTERNAL_SERVER_ERROR;
+        assert!(!GitHubError::is_not_found(
+            &GitHubError::Http { status, url }.into()
+        ));
+        assert!(!GitHubError::is_not_found(&anyhow::anyhow!("timeout")));
+    }
+}
@@ -1,3 +1,12 @@
 from .common import Mod
 from .common import MOD_VERSION
+from .common import MOD_SCHEMA
 from .common import mods_dump
+from .common import data_dump
+from .common import mods_split_dump
+from .common import sitemap_dump
+from .common import changes_dump
+from .common import resolve_dependencies
+from .common import dedup_mods
+from .common import mark_featured
+from .common import sanitize_html
@@ -0,0 +1,23 @@
+'''Atomic file writes, such that a crash mid-write never leaves a truncated
+file to be served, or loaded back as a corrupt cache.'''
+import os
+import tempfile
+from pathlib import Path
+
+def write_atomic(path, data):
+    '''Writes `data`, text or bytes, to a temporary file next to `path` and
+    then renames it into place, which replaces `path` all at once.'''
+    path = Path(path)
+    mode = 'wb' if isinstance(data, bytes) else 'w'
+    fd, tmp = tempfile.mkstemp(dir=path.parent, prefix=f".{path.name}.", suffix=".tmp")
+    try:
+        with os.fdopen(fd, mode) as f:
+            f.write(data)
+            f.flush()
+            os.fsync(f.fileno())
+        # temporary files are private, keep the mode of generated files
+        os.chmod(tmp, path.stat().st_mode if path.exists() else 0o644)
+        os.replace(tmp, path)
+    except BaseException:
+        os.unlink(tmp)
+        raise
@@ -67,18 +67,25 @@ def fix_urls(md, repo_name):
     replacers = set((on_match(x) for x in re.finditer(r'\[([^\]\[]*)\]\(([^\)]*)\)', md)))
     return functools.reduce(lambda md, x: md.replace(x[0], x[1]), replacers, md)
 
-def build_mod(repo_obj, icon):
+def build_mod(repo_obj, icon, accent=None):
     '''Build `Mod` object.'''
+    return Mod(**mod_fields(repo_obj, icon, accent))
+
+def mod_fields(repo_obj, icon, accent=None):
+    '''Build the keyword arguments of a `Mod` object.'''
     def parse_or_nothing(x):
         return ignore_sbrack.parse(x or "")
 
     r = repo_obj
     mods_name = parse_or_nothing(r.mod.name) if r.mod.name else r.name
     mods_desc = parse_or_nothing(r.mod.description)
-    author = parse_or_nothing(r.mod.author)
+    # plain authors for searching, markup is kept in `author_markup` for display
+    markups = r.mod.author if isinstance(r.mod.author, list) else [r.mod.author]
+    markups = [ str(x) for x in markups if x ]
+    authors = [ x for x in (parse_or_nothing(x).strip() for x in markups) if x ]
     mindustry_name = r.name.split("/")[1].lower().replace(" ", "-")
     
-    return Mod(
+    return dict(
         name=mods_name,
         name_markup=r.mod.displayName or r.mod.name,
         link=f"https://github.com/{r.name}",
@@ -86,22 +93,36 @@ def build_mod(repo_obj, icon):
         desc=mods_desc,
         desc_markup=r.mod.description,
         icon=icon,
+        accent=accent,
         stars=r.stars,
-        author=author.strip(),
-        author_markup=r.mod.author,
+        author=", ".join(authors),
+        authors=authors,
+        author_markup=", ".join(markups) or None,
         date=str(r.date),
         date_tt=time.mktime(r.date.timetuple()),
+        created=str(r.created) if r.created else "",
+        created_tt=time.mktime(r.created.timetuple()) if r.created else 0.0,
         readme=fix_urls(r.readme or '', r.name),
+        readme_unavailable=r.readme is None,
+        license=r.license,
         version=r.mod.version,
+        size_kb=r.size_kb,
+        activity=r.activity or [],
+        archived=r.archived,
         assets=list(r.assets),
         contents=list(r.contents),
         display_name=r.mod.displayName,
         default_branch=r.default_branch,
         min_game_version=r.mod.minGameVersion,
+        is_js_mod=r.mod.mainScript is not None,
+        dependencies=[ str(x) for x in r.mod.dependencies or [] ],
+        last_commit_msg=r.commit_msg,
+        changelog=fix_urls(r.changelog, r.name) if r.changelog else None,
+        bundles=sorted(r.bundles),
     )
 
 
-def build_mods(repo_objs, icons):
+def build_mods(repo_objs, icons, accents):
     '''Build list of `Mod` objects.'''
-    return [ build_mod(x, icons[x.name])
+    return [ build_mod(x, icons[x.name], accents.get(x.name))
              for x in repo_objs ]
@@ -0,0 +1,33 @@
+'''Module for recording repositories which failed to be fetched or parsed, such
+that they can be reported from the frontend.'''
+import json
+
+from common.config import FAILURES_CACHE_PATH, DATA_PATH, MOD_META_VERSION
+from common.atomic import write_atomic
+
+def failures_load():
+    '''Loads the `{ repo: reason }` failures, or an empty dict.'''
+    if FAILURES_CACHE_PATH.exists():
+        with open(FAILURES_CACHE_PATH, 'r') as f:
+            return json.load(f)
+    return {}
+
+def failures_dump(failures):
+    write_atomic(FAILURES_CACHE_PATH, json.dumps(failures))
+
+def record_failure(repo_name, reason):
+    print(f"[error] {repo_name} -- {reason}")
+    failures = failures_load()
+    failures[repo_name] = reason
+    failures_dump(failures)
+
+def clear_failure(repo_name):
+    failures = failures_load()
+    if failures.pop(repo_name, None) is not None:
+        failures_dump(failures)
+
+def write_failures():
+    '''Writes the failures for the frontend, as a list of `Failure`.'''
+    failures = [ dict(repo=repo, reason=reason)
+                 for repo, reason in sorted(failures_load().items()) ]
+    write_atomic(DATA_PATH / f"failures.{MOD_META_VERSION}.json", json.dumps(failures))
@@ -1,20 +1,23 @@
 '''Module for caching GitHub data.'''
 from pathlib import Path
-from dataclasses import dataclass, asdict
-from typing import List, Optional, Set
+from dataclasses import dataclass, asdict, field
+from typing import List, Optional, Set, Union
 from datetime import datetime
 import dateutil
 
 import json
+import time
 import hjson
 from base64 import b64decode
 from github import GithubException, UnknownObjectException
+from requests.exceptions import ConnectionError
 
 from common.config import GITHUB_REPO_CACHE_PATH, gh
+from common.caching.failures import record_failure, clear_failure
 
 def try_branches(repo, branch_list):
     if repo.default_branch is not None:
-        [repo.default_branch] + branch_list
+        branch_list = [repo.default_branch] + branch_list
     for branch_name in branch_list:
         try:
             branch = repo.get_branch(branch_name)
@@ -36,6 +39,75 @@ def get_file(repo, filename):
     except GithubException as e:
         print(f"[error] unable to find {filename} in {repo.name}")
 
+def get_changelog(repo):
+    '''Gets the `CHANGELOG.md` of a repository, or None. Most repositories
+    have none, which isn't logged, unlike other errors.'''
+    try:
+        return b64decode(repo.get_contents("CHANGELOG.md").content).decode('utf8')
+    except UnknownObjectException:
+        return None
+    except GithubException as e:
+        print(f"[error] unable to fetch CHANGELOG.md of {repo.full_name} -- {e}")
+        return None
+
+# README paths tried in order, since repositories don't agree on the case or
+# extension of its file name, and some keep it under `docs/`
+README_CANDIDATES = [
+    "README.md",
+    "readme.md",
+    "Readme.md",
+    "README.MD",
+    "README.markdown",
+    "readme.markdown",
+    "docs/README.md",
+]
+
+def get_readme(repo, attempts=3, backoff=2):
+    '''Gets the README from a repository, from the first of `README_CANDIDATES`
+    which exists, retrying transient errors with an exponential backoff.
+    Returns the README and the path it was found at, `("", None)` if the
+    repository has no README, or `(None, None)` if it couldn't be fetched.'''
+    for path in README_CANDIDATES:
+        for attempt in range(attempts):
+            try:
+                return b64decode(repo.get_contents(path).content).decode('utf8'), path
+            except UnknownObjectException:
+                break
+            except (GithubException, ConnectionError) as e:
+                print(f"[error] unable to fetch {path} of {repo.full_name} "
+                      f"(attempt {attempt + 1}/{attempts}) -- {e}")
+                if attempt + 1 < attempts:
+                    time.sleep(backoff ** attempt)
+        else:
+            # every attempt failed, the next candidates would hide it
+            return None, None
+    return "", None
+
+def get_activity(repo, attempts=3, backoff=2):
+    '''Weekly commit counts of the last year, oldest first. GitHub computes
+    them in the background and answers with nothing until they're ready, in
+    which case this retries, and returns `[]` if they're still not ready.'''
+    for attempt in range(attempts):
+        try:
+            weeks = repo.get_stats_commit_activity()
+        except (GithubException, ConnectionError) as e:
+            print(f"[error] unable to fetch activity of {repo.full_name} -- {e}")
+            return []
+        if weeks:
+            return [ week.total for week in weeks ]
+        if attempt + 1 < attempts:
+            time.sleep(backoff ** attempt)
+    return []
+
+def get_license(repo):
+    '''License detected by GitHub, by SPDX identifier when it's recognized.'''
+    license = repo.license
+    if license is None:
+        return None
+    if license.spdx_id and license.spdx_id != "NOASSERTION":
+        return license.spdx_id
+    return license.name
+
 ASSETS = set(x.strip() for x in '''
 content
 bundles
@@ -61,6 +133,12 @@ def get_assets(repo):
     res = repo.get_dir_contents("/")
     return set(x.name for x in res if x.name in ASSETS)
 
+def get_bundles(repo):
+    '''Returns a set of the file names in the bundles directory, such as
+    `bundle_ru.properties`, whose locales show which languages a mod has.'''
+    res = repo.get_dir_contents("/bundles")
+    return set(x.name for x in res if x.type == "file")
+
 def get_contents(repo):
     '''Returns a set of contents found in repository.'''
     res = repo.get_dir_contents("/content")
@@ -74,8 +152,8 @@ class ModInfo:
     name: str = None
     '''mod.json description.'''
     description: str = None
-    '''mod.json author.'''
-    author: str = None
+    '''mod.json author, or authors, since it may be an array.'''
+    author: Union[str, List[str]] = None
     '''mod.json version.'''
     version: str = None
     '''mod.json dependencies.'''
@@ -86,7 +164,7 @@ class ModInfo:
     minGameVersion: Optional[str] = None
     '''mod.json hidden.'''
     hidden: bool = None
-
+    '''mod.json main script, present in JavaScript mods.'''
     mainScript: str = None
 
     def __repr__(self):
@@ -142,8 +220,8 @@ class Repo:
     sha: str
     '''Mod.json of repository.'''
     mod: Optional[ModInfo]
-    '''README.md of the repository.'''
-    readme: str
+    '''README of the repository, or None if it couldn't be fetched.'''
+    readme: Optional[str]
     '''A set of assets found in the repo.'''
     assets: Set[str]
     '''A set of contents found in the repo.'''
@@ -152,6 +230,24 @@ class Repo:
     default_branch: str
     '''The minimum game version.'''
     min_game_version: Optional[str] = None
+    '''Last commit message.'''
+    commit_msg: Optional[str] = None
+    '''CHANGELOG.md of the repository.'''
+    changelog: Optional[str] = None
+    '''License SPDX identifier, or its name if it has none.'''
+    license: Optional[str] = None
+    '''Repository size in kilobytes, as reported by GitHub.'''
+    size_kb: Optional[int] = None
+    '''Weekly commit counts of the last year, oldest first.'''
+    activity: Optional[List[int]] = None
+    '''Whether the repository is archived, which freezes it as read-only.'''
+    archived: bool = False
+    '''Path the README was found at, one of `README_CANDIDATES`.'''
+    readme_path: Optional[str] = None
+    '''A set of the file names in the bundles directory.'''
+    bundles: Set[str] = field(default_factory=set)
+    '''When the repository was created.'''
+    created: Optional[datetime] = None
 
     def __repr__(self):
         return f"Repo(name=\"{self.name}\")"
@@ -165,39 +261,60 @@ class Repo:
     @staticmethod
     def from_repo(repo):
         branch = try_branches(repo, ["master", "main"])
+        if branch is None:
+            record_failure(repo.full_name, "no default, master or main branch found")
+            return None
         sha = branch.commit.sha
+        commit = repo.get_commit(sha).commit
         assets = get_assets(repo)
         contents = get_contents(repo) if 'content' in assets else set()
+        bundles = get_bundles(repo) if 'bundles' in assets else set()
         modinfo = ModInfo.from_repo(repo)
         if modinfo is None:
+            record_failure(repo.full_name, "unable to find or parse mod.json or mod.hjson")
             return None
+        clear_failure(repo.full_name)
+        readme, readme_path = get_readme(repo)
         return Repo(
             repo.full_name,
             stars=repo.stargazers_count,
-            date=repo.get_commit(sha).commit.author.date,
+            date=commit.author.date,
             sha=sha,
             mod=modinfo,
-            readme=get_file(repo, "README.md") or "",
+            readme=readme,
             assets=assets,
             contents=contents,
             default_branch=branch.name,
             min_game_version=modinfo.minGameVersion,
+            commit_msg=commit.message,
+            changelog=get_changelog(repo),
+            license=get_license(repo),
+            size_kb=repo.size,
+            activity=get_activity(repo),
+            archived=repo.archived,
+            readme_path=readme_path,
+            bundles=bundles,
+            created=repo.created_at,
         )
 
     def archive_link(self):
-        return f"https://github.com/{self.repo}/archive/master.zip"
+        return f"https://github.com/{self.name}/archive/{self.default_branch}.zip"
 
     def into_dict(self):
         '''Called when the object is about to be serialized.'''
         return { **asdict(self),
                  'date': str(self.date),
+                 'created': str(self.created) if self.created else None,
                  'assets': sorted(list(self.assets)),
-                 'contents': sorted(list(self.contents)) }
+                 'contents': sorted(list(self.contents)),
+                 'bundles': sorted(list(self.bundles)) }
 
     def from_dict(d):
         '''Called when the object is being deserialized.'''
         return Repo(**{ **d,
                         "date": dateutil.parser.parse(d["date"]),
+                        "created": dateutil.parser.parse(d["created"]) if d.get("created") else None,
                         "mod": ModInfo(**d["mod"]),
                         "assets": set(d["assets"]),
-                        "contents": set(d["contents"]) })
+                        "contents": set(d["contents"]),
+                        "bundles": set(d.get("bundles", [])) })
@@ -1,6 +1,8 @@
 '''Module for caching icons and icon paths.'''
 from github import GithubException
 from base64 import b64decode
+from io import BytesIO
+from PIL import Image
 
 import json
 import hashlib
@@ -8,6 +10,7 @@ import time
 from pathlib import Path
 
 from common.config import IMAGES_JSON, gh
+from common.atomic import write_atomic
 
 def update_icon(repo_name, image_path=None, skip=False, cache_time=60*60*24):
     '''Downloads an image from the target repository, and runs it through sha256
@@ -32,8 +35,9 @@ def update_icon(repo_name, image_path=None, skip=False, cache_time=60*60*24):
         cache_data[repo_name] = {}
     repo_data = cache_data[repo_name]
 
-    # checks if icon exists and if it's old enough to consider updating
-    if "icon-hash" in repo_data:
+    # checks if icon exists and if it's old enough to consider updating,
+    # entries cached before accents were computed are updated right away
+    if "icon-hash" in repo_data and "icon-accent" in repo_data:
         old = repo_data["icon-path"]
         cached_time = time.time() - repo_data["time-cached"]
         if not cached_time > cache_time:
@@ -52,17 +56,19 @@ def update_icon(repo_name, image_path=None, skip=False, cache_time=60*60*24):
     else:
         data, data_path = _get_icon(gh_repo, "icon.png")
 
+    accent = None
     if data is not None:
+        accent = dominant_color(data)
         m = hashlib.sha256()
         m.update(data)
         data = m.hexdigest()
 
     cache_data[repo_name] = { "icon-hash": data,
                               "icon-path": data_path,
+                              "icon-accent": accent,
                               "time-cached": time.time() }
 
-    with open(IMAGES_JSON, "w") as f:
-        json.dump(cache_data, f)
+    write_atomic(IMAGES_JSON, json.dumps(cache_data))
 
     return data_path
 
@@ -76,6 +82,30 @@ def _get_icon(gh_repo, path):
     except GithubException as e:
         return None, None
 
+def dominant_color(data, size=16):
+    '''Returns the average color of the visible pixels of an image, downscaled
+    to `size` by `size`, as a `"#rrggbb"` hex string, or `None` if the image
+    can't be decoded or is fully transparent.'''
+    try:
+        image = Image.open(BytesIO(data)).convert("RGBA").resize((size, size))
+    except (OSError, ValueError) as e:
+        print(f"[error] unable to decode icon -- {e}")
+        return None
+    visible = [ (r, g, b) for r, g, b, a in image.getdata() if a >= 128 ]
+    if not visible:
+        return None
+    r, g, b = ( sum(x) // len(visible) for x in zip(*visible) )
+    return f"#{r:02x}{g:02x}{b:02x}"
+
+def icon_accents(repo_names):
+    '''Returns a dict of `repo_name` to the cached accent color of its icon,
+    which `update_icons` computes.'''
+    if not IMAGES_JSON.exists():
+        return {}
+    with open(IMAGES_JSON) as f:
+        cache_data = json.load(f)
+    return { x: cache_data.get(x, {}).get("icon-accent") for x in repo_names }
+
 def update_icons(repo_names):
     '''Returns a dict of `repo_name` to `icon_path`'''
     def update_mod_icon(repo_name):
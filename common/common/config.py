@@ -10,17 +10,26 @@ MOD_META_VERSION = MOD_VERSION
 try:
     GITHUB_TOKEN = os.environ['GITHUB_TOKEN']
 except KeyError:
-    GITHUB_TOKEN = none
+    GITHUB_TOKEN = None
+
+SITE_URL = "http://mindustry-mods.metasimon.space/"
 
 WEB_DIR = Path("/web") / "mindustry-mods"
 
 CACHE_PATH = WEB_DIR / "cache"
 STATIC_DIR = WEB_DIR / "www" / "static"
 DATA_PATH = STATIC_DIR / "data"
+SITEMAP_PATH = STATIC_DIR / "sitemap.xml"
+PAGES_DIR = WEB_DIR / "www" / "m"
 
 IMAGES_JSON = CACHE_PATH / "images.json"
 GITHUB_REPO_CACHE_PATH = CACHE_PATH / "github-repo-cache.json"
 SHA_CACHE_PATH = CACHE_PATH / "sha.json"
+FAILURES_CACHE_PATH = CACHE_PATH / "failures.json"
+REPORT_PATH = DATA_PATH / "report.json"
+
+# hand-picked by the maintainers, so it's kept with the code
+FEATURED_PATH = Path(__file__).parent / "featured.txt"
 
 DATA_PATH.mkdir(exist_ok=True)
 CACHE_PATH.mkdir(exist_ok=True)
@@ -17,9 +17,10 @@ from base64 import b64decode
 # Application Stuff
 import subprocess
 import time
+import gzip
 import click
 #import appdirs
-from github import GithubException, UnknownObjectException
+from github import GithubException, UnknownObjectException, RateLimitExceededException
 from datetime import datetime, timezone
 
 # Generation
@@ -36,27 +37,109 @@ import common
 from common.minfmt import ignore_sbrack
 from common.caching import icons
 from common.config import DATA_PATH, MOD_META_VERSION, GITHUB_TOKEN, gh, GITHUB_REPO_CACHE_PATH
+from common.config import SITE_URL, SITEMAP_PATH, FEATURED_PATH
 from common.caching.ghrepo import Repo
-from common.caching.icons import update_icons
+from common.caching.icons import update_icons, icon_accents
 from common.caching import build_mods
+from common.pages import write_pages
 from common.caching.ghrepo import try_branches
+from common.caching.failures import record_failure, clear_failure, write_failures
 from common import mods_dump
+from common import data_dump
+from common import mods_split_dump
+from common import sitemap_dump
+from common import changes_dump
+from common import resolve_dependencies
+from common import mark_featured
+from common import dedup_mods
+from common.atomic import write_atomic
+from common.report import RunReport
 
-def update_frontend_data():
+# number of mods in the first data, about a screen of cards
+FIRST_DATA_LEN = 20
+
+def gzip_dump(text, path):
+    '''Writes a gzip compressed copy of the data, which the web server sends
+    with `Content-Encoding: gzip`. Sizes are logged to keep track of savings.'''
+    raw = text.encode('utf8')
+    compressed = gzip.compress(raw, mtime=0)
+    write_atomic(path, compressed)
+    print(f"[log] {path.name}: {len(compressed)} bytes, from {len(raw)} bytes "
+          f"({100 * len(compressed) // max(len(raw), 1)}%)")
+
+def write_if_changed(path, text):
+    '''Writes a file, leaving it untouched if its content is the same.'''
+    if path.exists() and path.read_text() == text:
+        return
+    write_atomic(path, text)
+
+def split_data_dump(mods):
+    '''Writes one json file per mod, keyed by its endpoint, and an index.'''
+    index, files = mods_split_dump(mods)
+    mods_dir = DATA_PATH / f"mods.{MOD_META_VERSION}"
+    mods_dir.mkdir(exist_ok=True)
+    write_if_changed(DATA_PATH / f"index.{MOD_META_VERSION}.json", index)
+    endpoints = set()
+    for endpoint, text in files:
+        endpoints.add(f"{endpoint}.json")
+        write_if_changed(mods_dir / f"{endpoint}.json", text)
+    for path in mods_dir.glob("*.json"):
+        if path.name not in endpoints:
+            path.unlink()
+
+def write_changes(old_path, mods):
+    '''Writes the mods added, updated and removed since the listing at
+    `old_path`, if any changed, such that `changes.json` always has the
+    latest changes.'''
+    old = old_path.read_text() if old_path.exists() else None
+    changes = changes_dump(old, mods, time.time())
+    if changes is not None:
+        write_atomic(DATA_PATH / "changes.json", changes)
+
+def update_frontend_data(split_data=False, include_archived=False, pretty_json=False,
+                         limit=None):
+    '''Writes the listing from the cached repositories, leaving out archived
+    repositories unless `include_archived`, as their authors froze them. The
+    listing is minified, unless `pretty_json` for debugging, and only has the
+    `limit` most recently updated repositories if any, for a smaller
+    `modmeta.json` while developing the frontend.'''
     repos = repo_load()
+    if not include_archived:
+        repos = [ x for x in repos if not x.archived ]
+    if limit is not None:
+        repos = sorted(repos, key=lambda x: x.date, reverse=True)[:limit]
     icons = update_icons([ x.name for x in repos ])
-    mods = build_mods(repos, icons)
+    accents = icon_accents([ x.name for x in repos ])
+    mods, merged = dedup_mods(build_mods(repos, icons, accents))
+    for kept, dropped in merged:
+        print(f"[log] duplicate -- kept {kept} over {dropped}")
+    mods = resolve_dependencies(mods)
+    mods = mark_featured(mods, FEATURED_PATH.read_text() if FEATURED_PATH.exists() else "")
     mods = list(reversed(sorted(mods, key=lambda x: x.date_tt())))
-    json_string = mods_dump(mods)
-    with open(DATA_PATH / f"modmeta.{MOD_META_VERSION}.json", 'w') as f:
-        f.write(json_string)
+    json_string = mods_dump(mods, pretty_json)
+    data_string = data_dump(mods, time.time(), pretty_json)
+    write_changes(DATA_PATH / "modmeta.latest.json", mods)
+    write_atomic(DATA_PATH / f"modmeta.{MOD_META_VERSION}.json", data_string)
+    # listed by the frontend while it downloads the rest
+    write_atomic(DATA_PATH / f"modmeta.{MOD_META_VERSION}.first.json",
+                 mods_dump(mods[:FIRST_DATA_LEN], pretty_json))
+    # unversioned copy for third party tools, see `schema.json`
+    write_atomic(DATA_PATH / "modmeta.latest.json", json_string)
+    write_if_changed(DATA_PATH / "schema.json", common.MOD_SCHEMA)
+    gzip_dump(data_string, DATA_PATH / f"modmeta.{MOD_META_VERSION}.json.gz")
+    if split_data:
+        split_data_dump(mods)
+    write_atomic(SITEMAP_PATH, sitemap_dump(mods, SITE_URL))
+    write_pages(repos, icons)
+    write_failures()
 
-def search_repositories_recent(sha_list):
+def search_repositories_recent(sha_list, report, limit=None):
     '''Search for repositories on GitHub. Given an old list of `sha` values,
     helps this function minimize API calls, as search results are ordered by
-    when they've been updated.'''
+    when they've been updated. Fetched repositories are recorded in `report`.
+    At most `limit` repositories are fetched, if any.'''
     paginated_list = gh.search_repositories("mindustry-mod", sort="updated")
-    for repo in paginated_list[:100]:
+    for repo in paginated_list[:100 if limit is None else min(100, limit)]:
         branch = try_branches(repo, ["master", "main"])
         if branch is None:
             continue
@@ -64,15 +147,16 @@ def search_repositories_recent(sha_list):
             break
         else:
             repo_obj = Repo.from_repo(repo)
+            report.record(repo.full_name, repo_obj)
             if repo_obj is not None:
                 yield repo_obj
 
-def update_repositories_recent():
+def update_repositories_recent(report, limit=None):
     '''The function updates the most recently updated repositories.
     Old repositories wont get updated, which makes it the most effecient.'''
     repo_objs = repo_load()
     sha_list = [ repo.sha for repo in repo_objs ]
-    for repo_i in search_repositories_recent(sha_list):
+    for repo_i in search_repositories_recent(sha_list, report, limit):
         print(f"[log] new entry -- {repo_i.name}")
         for j, repo_j in enumerate(repo_objs):
             if repo_i.name == repo_j.name:
@@ -81,15 +165,47 @@ def update_repositories_recent():
         repo_objs.append(repo_i)
     repo_dump(repo_objs)
 
-def update_repositories_cached(dry_run=False):
-    '''This function updates all repositories cached.'''
+def latest_sha(repo_obj):
+    '''Latest commit sha of the cached default branch, in a single request,
+    since the repository itself isn't fetched. `None` if the branch is gone,
+    such as when the default branch was renamed.'''
+    repo = gh.get_repo(repo_obj.name, lazy=True)
+    try:
+        return repo.get_branch(repo_obj.default_branch).commit.sha
+    except GithubException:
+        return None
+
+def update_repositories_cached(dry_run=False, incremental=False):
+    '''This function updates all repositories cached. When `incremental`,
+    repositories whose latest commit is the cached one are kept as is, which
+    only costs one request each. Cached entries are also kept when they fail
+    to be fetched again, unless the repository is gone (404), as when it was
+    deleted or made private, in which case its entry is removed.'''
+    report = RunReport()
     repo_objs = repo_load()
-    remove = []
+    unchanged = 0
     for i, repo_obj in enumerate(repo_objs):
-        repo = gh.get_repo(repo_obj.name)
-        repo_objs[i] = Repo.from_repo(repo)
+        try:
+            if incremental and latest_sha(repo_obj) == repo_obj.sha:
+                unchanged += 1
+                continue
+            repo = gh.get_repo(repo_obj.name)
+        except UnknownObjectException:
+            print(f"[log] removed -- {repo_obj.name}, repository not found")
+            clear_failure(repo_obj.name)
+            report.removed.append(repo_obj.name)
+            repo_objs[i] = None
+            continue
+        new_obj = Repo.from_repo(repo)
+        report.record(repo_obj.name, new_obj)
+        if new_obj is not None:
+            repo_objs[i] = new_obj
+    repo_objs = [ x for x in repo_objs if x is not None ]
+    if incremental:
+        print(f"[log] incremental -- {unchanged} of {len(repo_objs)} unchanged")
     if not dry_run:
         repo_dump(repo_objs)
+        report.finish(gh.get_rate_limit().core.remaining)
 
 def repo_load():
     '''Loads Repo objects from json file if exist,
@@ -102,29 +218,34 @@ def repo_load():
                 repo_set.add(Repo.from_dict(x))
             return list(repo_set)
     else:
-        with open(PATH, 'w') as f:
-            json.dump([], f)
+        write_atomic(PATH, json.dumps([]))
         return []
 
 def repo_dump(repo_objs):
-    with open(GITHUB_REPO_CACHE_PATH, 'w') as f:
-        json.dump([ r.into_dict() for r in set(repo_objs)], f)
+    write_atomic(GITHUB_REPO_CACHE_PATH,
+                 json.dumps([ r.into_dict() for r in set(repo_objs)]))
         
-def update(i):
+def update(i, split_data=False, include_archived=False, pretty_json=False, limit=None):
     '''Takes PyGitHub instance and the mods-yaml data, and returns a modmeta, 
     which is generated data from what has been cached.'''
     if i % (60 * 5) == 0:
+        report = RunReport()
         try:
-            update_repositories_recent()
-            update_frontend_data()
+            update_repositories_recent(report, limit)
+            update_frontend_data(split_data, include_archived, pretty_json, limit)
             now = datetime.now()
             rate = gh.get_rate_limit()
+            report.finish(rate.core.remaining)
 
             print(f"done: {now}")
             print("rate:")
             print(f"  limit: {rate.core.limit}")
             print(f"  remaining: {rate.core.remaining}")
             print(f"  reset: {rate.core.reset.replace(tzinfo=timezone.utc).astimezone(tz=None)}")
+        except RateLimitExceededException as e:
+            report.rate_limit_hits += 1
+            report.finish(0)
+            print("[exception] ", e)
         except ConnectionError as e:
             # NOTE: catch connection errors like:
             #
@@ -133,6 +254,7 @@ def update(i):
             # - timeout or other general connection errors.
             # 
             # ...this is a bad solution.
+            report.finish()
             print("[exception] ", e)
     # if i % (60 * 60 * 6) == 0:
     #     update_repositories_cached()
@@ -143,8 +265,10 @@ def cli():
 
 @cli.command()
 @click.option("--dry-run", help="Don't write anything.")
-def fix(dry_run):
-    update_repositories_cached(dry_run)
+@click.option("--incremental", is_flag=True,
+              help="Only fetch repositories with new commits.")
+def fix(dry_run, incremental):
+    update_repositories_cached(dry_run, incremental)
 
 @cli.command()
 @click.argument("count", type=int)
@@ -156,7 +280,11 @@ def ls(count):
 
 @cli.command()
 @click.option("--un-authenticated", help="Ignore missing GitHub token.")
-def run(un_authenticated):
+@click.option("--split-data", is_flag=True, help="Also write one json file per mod, and an index.")
+@click.option("--include-archived", is_flag=True, help="Also list archived repositories.")
+@click.option("--pretty-json", is_flag=True, help="Pretty print the listing, for debugging.")
+@click.option("--limit", type=click.IntRange(min=1), help="Only fetch and list the N latest mods, for frontend development.")
+def run(un_authenticated, split_data, include_archived, pretty_json, limit):
     if GITHUB_TOKEN is None:
         if un_authenticated:
             print("[error] no github token")
@@ -165,7 +293,7 @@ def run(un_authenticated):
             print("[warn] no github token")
     i = 0
     while True:
-        update(i)
+        update(i, split_data, include_archived, pretty_json, limit)
         time.sleep(1)
         i += 1
         
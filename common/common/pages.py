@@ -0,0 +1,126 @@
+'''Module for pre-rendering a static html page per mod, at the `m/{endpoint}.html`
+path, such that link previews work and the content is crawlable. The frontend
+app is still loaded by the page, and renders over it.'''
+import math
+import jinja2
+import markdown
+from jinja2 import Markup
+
+from common.config import PAGES_DIR, SITE_URL
+from common.caching import mod_fields
+from common.atomic import write_atomic
+from common import sanitize_html
+
+PAGE = jinja2.Environment(autoescape=True).from_string('''<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8">
+    <base href="/">
+    <title>{{ name }} - Mindustry Mods</title>
+    <meta name="description" content="{{ desc }}">
+    <meta property="og:type" content="website">
+    <meta property="og:site_name" content="Mindustry Mods">
+    <meta property="og:title" content="{{ name }}">
+    <meta property="og:description" content="{{ desc }}">
+    <meta property="og:image" content="{{ icon }}">
+    <meta property="og:url" content="{{ url }}">
+    <meta name="twitter:card" content="summary">
+    <meta name="twitter:title" content="{{ name }}">
+    <meta name="twitter:description" content="{{ desc }}">
+    <meta name="twitter:image" content="{{ icon }}">
+    <link rel="canonical" href="{{ url }}">
+    <script type="application/ld+json">{{ structured_data|tojson }}</script>
+    <link rel="StyleSheet" href="/static/css/main.1.3.css" />
+    <link rel="StyleSheet" href="/static/css/header.css" />
+    <script type="module">
+      import init from '/static/pkg/frontend.js';
+      async function run() {
+          await init('/static/pkg/frontend_bg.wasm');
+      }
+      run();
+    </script>
+  </head>
+  <body>
+    <div id="app">
+      <header><a href="{{ url }}"><h1>Mindustry Mods</h1></a></header>
+      <div class="outside">
+        <h2>{{ name }}</h2>
+        <p>by {{ author }}</p>
+        <p class="description">{{ desc }}</p>
+        <p><a href="{{ link }}">repository</a></p>
+      </div>
+      <div class="outside">
+        <div class="markdown">{{ readme }}</div>
+      </div>
+    </div>
+  </body>
+</html>
+''')
+
+def endpoint_query(repo_name):
+    '''Same as `Mod::endpoint_query`, as in `"user--repo"`.'''
+    return repo_name.replace("/", "--")
+
+def icon_url(fields):
+    icon = (fields["icon"] or "icon.png").strip()
+    if icon.startswith(("http://", "https://")):
+        return icon
+    path = icon.lstrip("/")
+    return f"https://raw.githubusercontent.com/{fields['repo']}/{fields['default_branch']}/{path}"
+
+def star_rating(stars):
+    '''Maps stars to a 1 to 5 rating, since GitHub has no ratings: on a log
+    scale, such that 1000 stars and above rate 5.'''
+    return round(min(5.0, 1.0 + math.log10(stars + 1) * 4 / 3), 1)
+
+def structured_data(fields, url, icon):
+    '''Schema.org `SoftwareApplication` of a mod, as JSON-LD, for richer search
+    results.'''
+    data = {
+        "@context": "https://schema.org",
+        "@type": "SoftwareApplication",
+        "name": fields["name"],
+        "description": fields["desc"],
+        "author": [ { "@type": "Person", "name": x } for x in fields["authors"] ],
+        "url": url,
+        "image": icon,
+        "applicationCategory": "GameApplication",
+        "applicationSubCategory": "Mindustry mod",
+        "operatingSystem": "Windows, macOS, Linux, Android, iOS",
+        "offers": { "@type": "Offer", "price": 0, "priceCurrency": "USD" },
+        "dateModified": fields["date"],
+    }
+    if fields["version"]:
+        data["softwareVersion"] = fields["version"]
+    if fields["stars"] > 0:
+        data["aggregateRating"] = {
+            "@type": "AggregateRating",
+            "ratingValue": star_rating(fields["stars"]),
+            "ratingCount": fields["stars"],
+            "bestRating": 5,
+            "worstRating": 1,
+        }
+    return data
+
+def render_page(repo_obj, icon):
+    fields = mod_fields(repo_obj, icon)
+    query = endpoint_query(repo_obj.name)
+    url = f"{SITE_URL}?mod={query}"
+    icon = icon_url(fields)
+    return PAGE.render(
+        name=fields["name"],
+        author=fields["author"],
+        desc=fields["desc"],
+        link=fields["link"],
+        icon=icon,
+        url=url,
+        readme=Markup(sanitize_html(markdown.markdown(fields["readme"]))),
+        structured_data=structured_data(fields, url, icon),
+    )
+
+def write_pages(repo_objs, icons):
+    '''Writes one page per mod.'''
+    PAGES_DIR.mkdir(exist_ok=True)
+    for repo_obj in repo_objs:
+        html = render_page(repo_obj, icons[repo_obj.name])
+        write_atomic(PAGES_DIR / f"{endpoint_query(repo_obj.name)}.html", html)
@@ -0,0 +1,51 @@
+'''Module for summarizing a refresh, such that its quality can be monitored
+over time from `report.json`.'''
+import json
+import time
+from dataclasses import dataclass, field, asdict
+from typing import Dict, List, Optional
+
+from common.config import REPORT_PATH
+from common.caching.failures import failures_load
+from common.atomic import write_atomic
+
+@dataclass
+class RunReport:
+    '''Summary of a refresh.'''
+
+    '''Start of the refresh, as a UTC timestamp epoch in seconds.'''
+    started: float = field(default_factory=time.time)
+    '''Number of repositories fetched.'''
+    processed: int = 0
+    '''Number of repositories fetched and parsed successfully.'''
+    succeeded: int = 0
+    '''Reasons of the repositories which failed, by repository.'''
+    failed: Dict[str, str] = field(default_factory=dict)
+    '''Repositories removed from the cache, since they no longer exist.'''
+    removed: List[str] = field(default_factory=list)
+    '''Number of times the GitHub rate limit was exceeded.'''
+    rate_limit_hits: int = 0
+    '''GitHub requests remaining at the end of the refresh.'''
+    rate_limit_remaining: Optional[int] = None
+    '''Duration of the refresh, in seconds.'''
+    runtime: float = 0.0
+
+    def record(self, repo_name, repo_obj):
+        '''Records the outcome of fetching a repository, where `repo_obj` is
+        `None` if it failed, in which case its reason was recorded with
+        `record_failure`.'''
+        self.processed += 1
+        if repo_obj is None:
+            self.failed[repo_name] = failures_load().get(repo_name, "unknown")
+        else:
+            self.succeeded += 1
+
+    def finish(self, rate_limit_remaining=None):
+        '''Writes the report, with the runtime up to now.'''
+        self.runtime = round(time.time() - self.started, 3)
+        self.rate_limit_remaining = rate_limit_remaining
+        write_atomic(REPORT_PATH, json.dumps(asdict(self), indent=2))
+        print(f"[log] report -- {self.succeeded}/{self.processed} succeeded, "
+              f"{len(self.failed)} failed, {len(self.removed)} removed, "
+              f"{self.rate_limit_hits} rate limit hits, "
+              f"{self.runtime}s")
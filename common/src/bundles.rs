@@ -0,0 +1,80 @@
+//! Translation mods, which only ship `bundles`, and the locales of the
+//! bundle files, as in `bundle_pt_BR.properties`.
+
+/// Locale of the default `bundle.properties`, which the game is written in.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Locale of a bundle file name: `ru` for `bundle_ru.properties`, and the
+/// `DEFAULT_LOCALE` for `bundle.properties`. `None` for other files.
+pub fn locale(file_name: &str) -> Option<String> {
+    let stem = file_name.strip_suffix(".properties")?;
+    if stem == "bundle" {
+        return Some(DEFAULT_LOCALE.to_string());
+    }
+    let code = stem.strip_prefix("bundle_")?;
+    let valid = !code.is_empty()
+        && code.split('_').next().map_or(false, |x| !x.is_empty())
+        && code.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    Some(code.to_string()).filter(|_| valid)
+}
+
+/// Locales of bundle file names, sorted and without duplicates.
+pub fn locales(file_names: &[String]) -> Vec<String> {
+    let mut locales: Vec<String> = file_names.iter().filter_map(|x| locale(x)).collect();
+    locales.sort();
+    locales.dedup();
+    locales
+}
+
+/// Whether bundles are the only asset of a mod, which has no contents or
+/// scripts, as in mods only translating the game or other mods.
+pub fn is_translation(contents: &[String], assets: &[String], is_js_mod: bool) -> bool {
+    contents.is_empty() && !is_js_mod && !assets.is_empty() && assets.iter().all(|x| x == "bundles")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn strings(x: &[&str]) -> Vec<String> {
+        x.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn locale_codes() {
+        assert_eq!(locale("bundle_ru.properties").as_deref(), Some("ru"));
+        assert_eq!(locale("bundle_pt_BR.properties").as_deref(), Some("pt_BR"));
+        assert_eq!(locale("bundle.properties").as_deref(), Some(DEFAULT_LOCALE));
+        assert_eq!(locale("bundle_.properties"), None);
+        assert_eq!(locale("readme.md"), None);
+        assert_eq!(locale("bundle_ru.txt"), None);
+    }
+
+    #[test]
+    fn sorted_locales() {
+        let files = strings(&[
+            "bundle_uk_UA.properties",
+            "bundle.properties",
+            "notes.txt",
+            "bundle_de.properties",
+        ]);
+        assert_eq!(locales(&files), strings(&["de", "en", "uk_UA"]));
+    }
+
+    #[test]
+    fn translations() {
+        assert!(is_translation(&[], &strings(&["bundles"]), false));
+        assert!(!is_translation(
+            &[],
+            &strings(&["bundles", "sprites"]),
+            false
+        ));
+        assert!(!is_translation(&[], &strings(&["bundles"]), true));
+        assert!(!is_translation(
+            &strings(&["blocks"]),
+            &strings(&["bundles", "content"]),
+            false
+        ));
+        assert!(!is_translation(&[], &[], false));
+    }
+}
@@ -0,0 +1,56 @@
+//! Primary category of a mod, inferred from its contents, assets and main
+//! script, for browsing above the granular content tags.
+
+pub const CONTENT: &str = "content pack";
+pub const SCRIPTING: &str = "scripting mod";
+pub const TEXTURES: &str = "texture pack";
+pub const SCHEMATICS: &str = "schematic pack";
+pub const TRANSLATION: &str = "translation";
+pub const OTHER: &str = "other";
+
+/// Every category, in the order of the dropdown.
+pub const ALL: [&str; 6] = [CONTENT, SCRIPTING, TEXTURES, SCHEMATICS, TRANSLATION, OTHER];
+
+/// Infers the category, where new content takes precedence over scripts,
+/// and scripts over assets which only change existing content.
+pub fn infer(contents: &[String], assets: &[String], is_js_mod: bool) -> &'static str {
+    let has = |asset: &str| assets.iter().any(|x| x == asset);
+    if !contents.is_empty() {
+        CONTENT
+    } else if is_js_mod || has("scripts") {
+        SCRIPTING
+    } else if has("sprites-override") || has("sprites") || has("sounds") {
+        TEXTURES
+    } else if has("schematics") {
+        SCHEMATICS
+    } else if has("bundles") {
+        TRANSLATION
+    } else {
+        OTHER
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn strings(x: &[&str]) -> Vec<String> {
+        x.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn precedence() {
+        let blocks = strings(&["blocks"]);
+        assert_eq!(infer(&blocks, &strings(&["content", "scripts"]), true), CONTENT);
+        assert_eq!(infer(&[], &strings(&["sprites", "scripts"]), false), SCRIPTING);
+        assert_eq!(infer(&[], &strings(&["sprites"]), true), SCRIPTING);
+        assert_eq!(infer(&[], &strings(&["bundles", "sounds"]), false), TEXTURES);
+    }
+
+    #[test]
+    fn assets_only() {
+        assert_eq!(infer(&[], &strings(&["schematics"]), false), SCHEMATICS);
+        assert_eq!(infer(&[], &strings(&["bundles"]), false), TRANSLATION);
+        assert_eq!(infer(&[], &[], false), OTHER);
+    }
+}
@@ -0,0 +1,92 @@
+//! Changes of the listing between two refreshes, as in mods which were
+//! added, updated or removed, for a changelog of the listing itself.
+
+use crate::{split::IndexEntry, Mod};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Changes of a refresh, by repository.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+pub struct Changes {
+    /// refresh UTC timestamp epoch in seconds
+    pub refreshed: f64,
+    /// mods which weren't listed before
+    pub added: Vec<IndexEntry>,
+    /// mods with another last commit than before
+    pub updated: Vec<IndexEntry>,
+    /// mods which aren't listed anymore
+    pub removed: Vec<IndexEntry>,
+}
+
+impl Changes {
+    /// Whether nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Changes from the `old` listing to the `new` one, at `refreshed`.
+pub fn diff(old: &[Mod], new: &[Mod], refreshed: f64) -> Changes {
+    let old_by_repo: HashMap<&str, &Mod> = old.iter().map(|m| (m.repo.as_str(), m)).collect();
+    let new_by_repo: HashMap<&str, &Mod> = new.iter().map(|m| (m.repo.as_str(), m)).collect();
+    let mut changes = Changes {
+        refreshed,
+        ..Default::default()
+    };
+    for m in new {
+        match old_by_repo.get(m.repo.as_str()) {
+            None => changes.added.push(m.into()),
+            Some(before) if before.date_tt.to_bits() != m.date_tt.to_bits() => {
+                changes.updated.push(m.into())
+            }
+            Some(_) => {}
+        }
+    }
+    changes.removed = old
+        .iter()
+        .filter(|m| !new_by_repo.contains_key(m.repo.as_str()))
+        .map(IndexEntry::from)
+        .collect();
+    changes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example(repo: &str, date_tt: f64) -> Mod {
+        Mod {
+            repo: repo.into(),
+            date_tt,
+            ..Default::default()
+        }
+    }
+
+    fn repos(entries: &[IndexEntry]) -> Vec<&str> {
+        entries.iter().map(|x| x.repo.as_str()).collect()
+    }
+
+    #[test]
+    fn changed() {
+        let old = vec![
+            example("a/same", 1.0),
+            example("b/updated", 1.0),
+            example("c/removed", 1.0),
+        ];
+        let new = vec![
+            example("d/added", 3.0),
+            example("b/updated", 2.0),
+            example("a/same", 1.0),
+        ];
+        let changes = diff(&old, &new, 10.0);
+        assert_eq!(repos(&changes.added), vec!["d/added"]);
+        assert_eq!(changes.updated, vec![IndexEntry::from(&new[1])]);
+        assert_eq!(repos(&changes.removed), vec!["c/removed"]);
+    }
+
+    #[test]
+    fn unchanged() {
+        let mods = vec![example("a/same", 1.0)];
+        assert!(diff(&mods, &mods, 0.0).is_empty());
+    }
+}
@@ -6,6 +6,13 @@ pub struct Color {
     a: Option<u8>,
 }
 
+impl Color {
+    /// Red, green and blue channels, ignoring alpha.
+    pub fn rgb(&self) -> [u8; 3] {
+        [self.r, self.g, self.b]
+    }
+}
+
 impl ToString for Color {
     fn to_string(&self) -> String {
         let Color { r, g, b, a } = *self;
@@ -43,6 +50,8 @@ impl From<&str> for Color {
     }
 }
 
+/// Named colors of the game, from arc's `Colors` and the ones Mindustry adds
+/// to them.
 pub enum Name {
     White,
     LightGray,
@@ -71,6 +80,7 @@ pub enum Name {
     Brick,
     Red,
     Scarlet,
+    Crimson,
     Coral,
     Salmon,
     Pink,
@@ -78,6 +88,11 @@ pub enum Name {
     Purple,
     Violet,
     Maroon,
+    Accent,
+    Unlaunched,
+    Highlight,
+    Stat,
+    NegStat,
 }
 
 impl From<Name> for Color {
@@ -85,13 +100,19 @@ impl From<Name> for Color {
     fn from(input: Name) -> Self {
         use Name::*;
         match input {
-            Maroon     => 0xb03060_ff_u32,
+            NegStat    => 0xe55454_ff_u32,
+            Stat       => 0xffd37f_ff,
+            Highlight  => 0xffe0a5_ff,
+            Unlaunched => 0x8982ed_ff,
+            Accent     => 0xffd37f_ff,
+            Maroon     => 0xb03060_ff,
             Violet     => 0xee82ee_ff,
             Purple     => 0xa020f0_ff,
             Magenta    => 0xff00ff_ff,
             Pink       => 0xff69b4_ff,
             Salmon     => 0xfa8072_ff,
             Coral      => 0xff7f50_ff,
+            Crimson    => 0xdc143c_ff,
             Scarlet    => 0xff341c_ff,
             Red        => 0xff0000_ff,
             Brick      => 0xb22222_ff,
@@ -106,12 +127,12 @@ impl From<Name> for Color {
             Lime       => 0x32cd32_ff,
             Acid       => 0x7fff00_ff,
             Green      => 0x00ff00_ff,
-            Teal       => 0x008888_ff,
+            Teal       => 0x007f7f_ff,
             Cyan       => 0x00ffff_ff,
             Sky        => 0x87ceeb_ff,
             Slate      => 0x708090_ff,
             Royal      => 0x4169e1_ff,
-            Navy       => 0x000088_ff,
+            Navy       => 0x00007f_ff,
             Blue       => 0x0000ff_ff,
             Clear      =>          0,
             Black      => 0x000000_ff,
@@ -131,16 +152,17 @@ pub enum NameError<'a> {
 }
 
 impl Name {
+    /// Parses a color name, ignoring case like the game does.
     fn from_str(input: &str) -> Result<Self, NameError> {
         use Name::*;
-        Ok(match input {
+        Ok(match input.to_ascii_lowercase().as_str() {
             "clear" => Clear,
             "black" => Black,
 
             "white" => White,
-            "lightgray" => LightGray,
-            "gray" => Gray,
-            "darkgray" => DarkGray,
+            "lightgray" | "lightgrey" => LightGray,
+            "gray" | "grey" => Gray,
+            "darkgray" | "darkgrey" => DarkGray,
 
             "blue" => Blue,
             "navy" => Navy,
@@ -175,9 +197,15 @@ impl Name {
             "purple" => Purple,
             "violet" => Violet,
             "maroon" => Maroon,
-            "crimson" => Scarlet,
+            "crimson" => Crimson,
 
-            name => Err(NameError::Unknown(name))?,
+            "accent" => Accent,
+            "unlaunched" => Unlaunched,
+            "highlight" => Highlight,
+            "stat" => Stat,
+            "negstat" => NegStat,
+
+            _ => Err(NameError::Unknown(input))?,
         })
     }
 }
@@ -213,6 +241,62 @@ mod tests {
         assert_eq!(BLUE_FF, "blue".into());
     }
 
+    #[test]
+    fn every_name() {
+        let names = [
+            ("clear", "#00000000"),
+            ("black", "#000000ff"),
+            ("white", "#ffffffff"),
+            ("lightgray", "#bfbfbfff"),
+            ("gray", "#7f7f7fff"),
+            ("darkgray", "#3f3f3fff"),
+            ("blue", "#0000ffff"),
+            ("navy", "#00007fff"),
+            ("royal", "#4169e1ff"),
+            ("slate", "#708090ff"),
+            ("sky", "#87ceebff"),
+            ("cyan", "#00ffffff"),
+            ("teal", "#007f7fff"),
+            ("green", "#00ff00ff"),
+            ("acid", "#7fff00ff"),
+            ("lime", "#32cd32ff"),
+            ("forest", "#228b22ff"),
+            ("olive", "#6b8e23ff"),
+            ("yellow", "#ffff00ff"),
+            ("gold", "#ffd700ff"),
+            ("goldenrod", "#daa520ff"),
+            ("orange", "#ffa500ff"),
+            ("brown", "#8b4513ff"),
+            ("tan", "#d2b48cff"),
+            ("brick", "#b22222ff"),
+            ("red", "#ff0000ff"),
+            ("scarlet", "#ff341cff"),
+            ("crimson", "#dc143cff"),
+            ("coral", "#ff7f50ff"),
+            ("salmon", "#fa8072ff"),
+            ("pink", "#ff69b4ff"),
+            ("magenta", "#ff00ffff"),
+            ("purple", "#a020f0ff"),
+            ("violet", "#ee82eeff"),
+            ("maroon", "#b03060ff"),
+            ("accent", "#ffd37fff"),
+            ("unlaunched", "#8982edff"),
+            ("highlight", "#ffe0a5ff"),
+            ("stat", "#ffd37fff"),
+            ("negstat", "#e55454ff"),
+        ];
+        for (name, css) in names.iter() {
+            assert_eq!(Color::from(*name).to_string(), *css, "{}", name);
+        }
+    }
+
+    #[test]
+    fn aliases() {
+        assert_eq!(Color::from("grey"), Color::from("gray"));
+        assert_eq!(Color::from("RED"), RED_FF);
+        assert_eq!(Color::from("unknown"), Color::from("white"));
+    }
+
     #[test]
     fn formatting() {
         assert_eq!(RED_FF.to_string(), "#ff0000ff");
@@ -0,0 +1,93 @@
+//! Deduplication of mods listed under multiple repositories, such as forks
+//! and renamed repositories which are still found by the search.
+
+use crate::{dependencies, Mod};
+use std::collections::HashMap;
+
+/// Mods are duplicates when they have the same normalized name and author.
+fn key(m: &Mod) -> (String, String) {
+    (m.mindustry_name(), dependencies::normalize(&m.author))
+}
+
+/// Whether `a` should be kept over `b`: more stars first, then the most
+/// recent commit.
+fn preferred(a: &Mod, b: &Mod) -> bool {
+    (a.stars, a.date_tt) > (b.stars, b.date_tt)
+}
+
+/// Removes duplicate mods, keeping the preferred one of each in place of
+/// the first duplicate. Returns the `(kept, dropped)` repositories, for
+/// logging what was merged.
+pub fn dedup(mods: Vec<Mod>) -> (Vec<Mod>, Vec<(String, String)>) {
+    let mut index: HashMap<(String, String), usize> = HashMap::new();
+    let mut kept: Vec<Mod> = Vec::with_capacity(mods.len());
+    let mut merged = vec![];
+    for m in mods {
+        match index.get(&key(&m)) {
+            Some(&i) => {
+                if preferred(&m, &kept[i]) {
+                    merged.push((m.repo.clone(), kept[i].repo.clone()));
+                    kept[i] = m;
+                } else {
+                    merged.push((kept[i].repo.clone(), m.repo.clone()));
+                }
+            }
+            None => {
+                index.insert(key(&m), kept.len());
+                kept.push(m);
+            }
+        }
+    }
+    (kept, merged)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example(repo: &str, name: &str, stars: u32, date_tt: f64) -> Mod {
+        Mod {
+            repo: repo.into(),
+            name: name.into(),
+            author: "Someone".into(),
+            stars,
+            date_tt,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unique() {
+        let mods = vec![
+            example("a/one", "One", 1, 0.0),
+            example("a/two", "Two", 1, 0.0),
+        ];
+        let (mods, merged) = dedup(mods);
+        assert_eq!(mods.len(), 2);
+        assert!(merged.is_empty());
+    }
+
+    #[test]
+    fn more_stars() {
+        let mods = vec![
+            example("fork/mod", "Example Mod", 1, 9.0),
+            example("a/one", "One", 1, 0.0),
+            example("user/mod", "example-mod", 5, 0.0),
+        ];
+        let (mods, merged) = dedup(mods);
+        let repos: Vec<&str> = mods.iter().map(|x| x.repo.as_str()).collect();
+        assert_eq!(repos, vec!["user/mod", "a/one"]);
+        assert_eq!(merged, vec![("user/mod".into(), "fork/mod".into())]);
+    }
+
+    #[test]
+    fn more_recent() {
+        let mods = vec![
+            example("old/mod", "Mod", 3, 1.0),
+            example("new/mod", "Mod", 3, 2.0),
+        ];
+        let (mods, merged) = dedup(mods);
+        assert_eq!(mods[0].repo, "new/mod");
+        assert_eq!(merged, vec![("new/mod".into(), "old/mod".into())]);
+    }
+}
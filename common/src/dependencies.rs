@@ -0,0 +1,56 @@
+//! Cross-referencing of `mod.json` dependencies with the mods in the listing,
+//! to flag dependencies which aren't (or are no longer) listed.
+
+use crate::Mod;
+use std::collections::HashSet;
+
+/// Normalizes a mod name the way Mindustry does, when comparing a
+/// dependency to the name of a loaded mod.
+pub fn normalize(name: &str) -> String {
+    name.trim().to_lowercase().replace(" ", "-")
+}
+
+/// Sets the unresolved dependencies of every mod, given the full listing.
+pub fn resolve(mods: &mut [Mod]) {
+    let known: HashSet<String> = mods.iter().map(Mod::mindustry_name).collect();
+    for m in mods.iter_mut() {
+        m.unresolved_dependencies = m
+            .dependencies
+            .iter()
+            .filter(|dep| !known.contains(&normalize(dep)))
+            .cloned()
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn mod_named(name: &str, dependencies: &[&str]) -> Mod {
+        Mod {
+            name: name.to_string(),
+            dependencies: dependencies.iter().map(|x| x.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn normalized() {
+        assert_eq!(normalize("Example Mod"), "example-mod");
+        assert_eq!(normalize(" example-mod "), "example-mod");
+    }
+
+    #[test]
+    fn unresolved() {
+        let mut mods = vec![
+            mod_named("Base Lib", &[]),
+            mod_named("Addon", &["base-lib", "missing-lib"]),
+            mod_named("Other Addon", &["Base Lib"]),
+        ];
+        resolve(&mut mods);
+        assert!(mods[0].unresolved_dependencies.is_empty());
+        assert_eq!(mods[1].unresolved_dependencies, vec!["missing-lib"]);
+        assert!(mods[2].unresolved_dependencies.is_empty());
+    }
+}
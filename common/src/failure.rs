@@ -0,0 +1,78 @@
+//! Repositories which failed to be fetched or parsed, and links to report
+//! them as issues against the listing.
+
+use serde::{Deserialize, Serialize};
+
+/// Where new issues of the listing are opened.
+const NEW_ISSUE_URL: &str = "https://github.com/SimonWoodburyForget/mindustry-mods/issues/new";
+
+/// A repository which couldn't be listed.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct Failure {
+    /// ex: `"What42Pizza/Mindustry-Production-Mod"`
+    pub repo: String,
+    /// ex: `"unable to find or parse mod.json"`
+    pub reason: String,
+}
+
+/// Percent encodes everything but unreserved characters, for use in an url
+/// query component.
+fn encode(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                output.push(byte as char)
+            }
+            byte => output.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    output
+}
+
+impl Failure {
+    /// Link to a new GitHub issue, prefilled with the repository and the
+    /// reason it failed.
+    pub fn issue_link(&self) -> String {
+        let title = format!("Broken mod: {}", self.repo);
+        let body = format!(
+            "Repository: https://github.com/{}\nReason: {}\n",
+            self.repo, self.reason
+        );
+        format!(
+            "{}?title={}&body={}",
+            NEW_ISSUE_URL,
+            encode(&title),
+            encode(&body)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encoding() {
+        assert_eq!(encode("user--repo_1.0~"), "user--repo_1.0~");
+        assert_eq!(encode("a b/c"), "a%20b%2Fc");
+        assert_eq!(encode("é\n"), "%C3%A9%0A");
+    }
+
+    #[test]
+    fn link() {
+        let failure = Failure {
+            repo: "Anuken/ExampleMod".into(),
+            reason: "not found".into(),
+        };
+        assert_eq!(
+            failure.issue_link(),
+            format!(
+                "{}?title=Broken%20mod%3A%20Anuken%2FExampleMod\
+                 &body=Repository%3A%20https%3A%2F%2Fgithub.com%2FAnuken%2FExampleMod\
+                 %0AReason%3A%20not%20found%0A",
+                NEW_ISSUE_URL
+            )
+        );
+    }
+}
@@ -0,0 +1,46 @@
+//! Mods hand-picked by the maintainers, from a list of repositories, to
+//! highlight them beyond their stars.
+
+use crate::Mod;
+
+/// Repositories of a featured list, one per line, ignoring blank lines and
+/// `#` comments.
+pub fn parse(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(|line| line.split('#').next().unwrap_or("").trim())
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Sets whether every mod is featured, by its repository. GitHub owners and
+/// repositories are case insensitive.
+pub fn mark(mods: &mut [Mod], featured: &[&str]) {
+    for m in mods.iter_mut() {
+        m.featured = featured.iter().any(|x| x.eq_ignore_ascii_case(&m.repo));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_lines() {
+        let text = "# featured mods\n\nAnuken/ExampleMod\n  Someone/Other  # great\n";
+        assert_eq!(parse(text), vec!["Anuken/ExampleMod", "Someone/Other"]);
+        assert!(parse("# nothing yet\n").is_empty());
+    }
+
+    #[test]
+    fn mark_by_repo() {
+        let example = |repo: &str| Mod {
+            repo: repo.into(),
+            featured: true,
+            ..Default::default()
+        };
+        let mut mods = vec![example("Anuken/ExampleMod"), example("Someone/Other")];
+        mark(&mut mods, &["anuken/examplemod"]);
+        assert!(mods[0].featured);
+        assert!(!mods[1].featured);
+    }
+}
@@ -0,0 +1,86 @@
+//! Mindustry game versions, as declared by `minGameVersion` in `mod.json`.
+//!
+//! They're build numbers such as `"104"` or `"126.2"`, sometimes with a
+//! leading `v` or `build`, and are compared numerically part by part.
+
+use std::cmp::Ordering;
+
+/// Parses the numeric parts of a game version, or `None` if it has none or
+/// isn't a version at all.
+pub fn parse(input: &str) -> Option<Vec<u32>> {
+    let input = input.trim();
+    let input = input
+        .strip_prefix("build")
+        .or_else(|| input.strip_prefix('v'))
+        .unwrap_or(input)
+        .trim();
+    if input.is_empty() {
+        return None;
+    }
+    input.split('.').map(|x| x.trim().parse().ok()).collect()
+}
+
+/// Compares numeric parts, where missing parts count as zero, so that
+/// `"126"` and `"126.0"` are equal.
+fn compare_parts(a: &[u32], b: &[u32]) -> Ordering {
+    let len = a.len().max(b.len());
+    let part = |v: &[u32], i: usize| v.get(i).copied().unwrap_or(0);
+    (0..len)
+        .map(|i| part(a, i).cmp(&part(b, i)))
+        .find(|x| *x != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Compares game versions; versions which can't be parsed are ordered before
+/// every other version.
+pub fn compare(a: &str, b: &str) -> Ordering {
+    match (parse(a), parse(b)) {
+        (Some(a), Some(b)) => compare_parts(&a, &b),
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Whether the game version `target` satisfies the `minimum`. Versions which
+/// can't be parsed are considered compatible, since there's nothing to tell.
+pub fn satisfies(minimum: &str, target: &str) -> bool {
+    match (parse(minimum), parse(target)) {
+        (Some(minimum), Some(target)) => compare_parts(&target, &minimum) != Ordering::Less,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parsing() {
+        assert_eq!(parse("104"), Some(vec![104]));
+        assert_eq!(parse(" 126.2 "), Some(vec![126, 2]));
+        assert_eq!(parse("v105"), Some(vec![105]));
+        assert_eq!(parse("build 104.6"), Some(vec![104, 6]));
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("latest"), None);
+        assert_eq!(parse("104.x"), None);
+    }
+
+    #[test]
+    fn ordering() {
+        assert_eq!(compare("104", "105"), Ordering::Less);
+        assert_eq!(compare("126", "126.0"), Ordering::Equal);
+        assert_eq!(compare("126.2", "126.10"), Ordering::Less);
+        assert_eq!(compare("latest", "104"), Ordering::Less);
+    }
+
+    #[test]
+    fn satisfied() {
+        assert!(satisfies("104", "104"));
+        assert!(satisfies("104", "126.2"));
+        assert!(!satisfies("126", "104"));
+        assert!(!satisfies("126.2", "126.1"));
+        assert!(satisfies("latest", "104"));
+        assert!(satisfies("104", ""));
+    }
+}
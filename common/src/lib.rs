@@ -1,31 +1,137 @@
+pub mod bundles;
+pub mod category;
+pub mod changes;
 pub mod color;
+pub mod dedup;
+pub mod dependencies;
+pub mod failure;
+pub mod featured;
+pub mod game_version;
+pub mod links;
 pub mod markup;
+pub mod order;
+pub mod sanitize;
+pub mod screenshots;
+pub mod search;
+pub mod sitemap;
+pub mod split;
+pub mod stats;
+pub mod status;
 
 #[cfg(feature = "pyo3")]
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Mod struct version. If breaking changes occur, this version number is
 /// incremented, and access paths are changed, ensuring the cache is cleared
 /// from the backend all the way to the frontend.
-pub const MOD_VERSION: &str = "3.3";
+pub const MOD_VERSION: &str = "3.5";
+
+/// GitHub owners whose mods are considered official, which is the one place
+/// the trust list is maintained.
+pub const TRUSTED_ORGS: &[&str] = &["Anuken"];
+
+/// JSON schema of the serialized listing, a `Vec<Mod>`.
+pub const MOD_SCHEMA: &str = include_str!("schema.json");
 
 #[cfg(feature = "pyo3")]
 /// This module is implemented in Rust.
 #[pymodule]
 fn common(_py: Python, module: &PyModule) -> PyResult<()> {
+    /// Minified, unless `pretty`, which is easier to read while debugging.
     #[pyfn(module, "mods_dump")]
-    fn mods_dump(_py: Python, mods: Vec<Mod>) -> PyResult<String> {
-        Ok(serde_json::to_string(&mods).unwrap())
+    fn mods_dump(_py: Python, mods: Vec<Mod>, pretty: bool) -> PyResult<String> {
+        Ok(if pretty {
+            serde_json::to_string_pretty(&mods).unwrap()
+        } else {
+            serde_json::to_string(&mods).unwrap()
+        })
+    }
+
+    /// Listing of the frontend, with when it was generated, such that the
+    /// time it shows is the one of the mods it lists, even from a cache.
+    #[pyfn(module, "data_dump")]
+    fn data_dump(_py: Python, mods: Vec<Mod>, generated_at: f64, pretty: bool) -> PyResult<String> {
+        let data = serde_json::json!({ "generated_at": generated_at, "mods": mods });
+        Ok(if pretty {
+            serde_json::to_string_pretty(&data).unwrap()
+        } else {
+            serde_json::to_string(&data).unwrap()
+        })
+    }
+
+    #[pyfn(module, "dedup_mods")]
+    fn dedup_mods(_py: Python, mods: Vec<Mod>) -> PyResult<(Vec<Mod>, Vec<(String, String)>)> {
+        Ok(dedup::dedup(mods))
+    }
+
+    #[pyfn(module, "resolve_dependencies")]
+    fn resolve_dependencies(_py: Python, mut mods: Vec<Mod>) -> PyResult<Vec<Mod>> {
+        dependencies::resolve(&mut mods);
+        Ok(mods)
+    }
+
+    #[pyfn(module, "mark_featured")]
+    fn mark_featured(_py: Python, mut mods: Vec<Mod>, featured: &str) -> PyResult<Vec<Mod>> {
+        featured::mark(&mut mods, &featured::parse(featured));
+        Ok(mods)
+    }
+
+    #[pyfn(module, "sanitize_html")]
+    fn sanitize_html(_py: Python, html: &str) -> PyResult<String> {
+        Ok(sanitize::html(html))
+    }
+
+    #[pyfn(module, "mods_split_dump")]
+    fn mods_split_dump(_py: Python, mods: Vec<Mod>) -> PyResult<(String, Vec<(String, String)>)> {
+        Ok(split::split(&mods))
+    }
+
+    #[pyfn(module, "changes_dump")]
+    fn changes_dump(
+        _py: Python,
+        old: Option<&str>,
+        mods: Vec<Mod>,
+        refreshed: f64,
+    ) -> PyResult<Option<String>> {
+        // nothing to compare the first listing to, or an unreadable one to
+        let old: Vec<Mod> = match old.map(serde_json::from_str) {
+            Some(Ok(old)) => old,
+            _ => return Ok(None),
+        };
+        let changes = changes::diff(&old, &mods, refreshed);
+        Ok(Some(serde_json::to_string(&changes).unwrap()).filter(|_| !changes.is_empty()))
+    }
+
+    #[pyfn(module, "sitemap_dump")]
+    fn sitemap_dump(_py: Python, mods: Vec<Mod>, base_url: &str) -> PyResult<String> {
+        Ok(sitemap::sitemap(base_url, &mods))
     }
 
     module.setattr("MOD_VERSION", MOD_VERSION)?;
+    module.setattr("MOD_SCHEMA", MOD_SCHEMA)?;
     module.add_class::<Mod>()?;
     Ok(())
 }
 
+/// Maximum length of a commit subject, in characters.
+const COMMIT_SUBJECT_LEN: usize = 72;
+
+/// First line of a commit message, truncated to `COMMIT_SUBJECT_LEN`.
+pub fn commit_subject(message: &str) -> String {
+    let line = message.lines().next().unwrap_or("").trim();
+    if line.chars().count() > COMMIT_SUBJECT_LEN {
+        let mut subject: String = line.chars().take(COMMIT_SUBJECT_LEN - 1).collect();
+        subject.push('…');
+        subject
+    } else {
+        line.to_string()
+    }
+}
+
 #[cfg_attr(feature = "pyo3", pyclass)]
-#[derive(Deserialize, Serialize, Clone, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 pub struct Mod {
     /// mod name
     pub name: String,
@@ -39,26 +145,85 @@ pub struct Mod {
     /// short description with markup
     pub desc_markup: Option<String>,
     pub icon: Option<String>,
+    /// average color of the icon, as in `"#rrggbb"`
+    #[serde(default)]
+    pub accent: Option<String>,
     /// repository stars
     pub stars: u32,
-    /// author name
+    /// author name, or the author names joined with `", "`
     pub author: String,
+    /// author names, since `mod.json` may list several
+    #[serde(default)]
+    pub authors: Vec<String>,
     /// author name with markup
     pub author_markup: Option<String>,
     /// last commit ISO formatted datetime
     pub date: String,
     /// last commit UTC timestamp epoch in seconds
     pub date_tt: f64,
+    /// repository creation ISO formatted datetime, empty when unknown
+    #[serde(default)]
+    pub created: String,
+    /// repository creation UTC timestamp epoch in seconds, `0` when unknown
+    #[serde(default)]
+    pub created_tt: f64,
+    /// subject line of the last commit message
+    #[serde(default)]
+    pub last_commit_msg: Option<String>,
     pub readme: String,
+    /// whether the README couldn't be fetched, as opposed to being missing
+    #[serde(default)]
+    pub readme_unavailable: bool,
+    /// SPDX identifier of the license, or its name when it has none
+    #[serde(default)]
+    pub license: Option<String>,
+    /// absolute urls of the images embedded in the README
+    #[serde(default)]
+    pub screenshots: Vec<String>,
+    /// weekly commit counts of the last year, oldest first
+    #[serde(default)]
+    pub activity: Vec<u32>,
+    /// CHANGELOG.md of the repository, if it has one
+    #[serde(default)]
+    pub changelog: Option<String>,
     pub version: Option<String>,
+    /// size of the repository in kilobytes, as reported by GitHub
+    #[serde(default)]
+    pub size_kb: Option<u32>,
     pub assets: Vec<String>,
     pub contents: Vec<String>,
     /// markup encoded name
-    #[serde(rename = "camelCase")]
     pub display_name: Option<String>,
     /// default repository branch (aka: master or main)
     pub default_branch: String,
     pub min_game_version: Option<String>,
+    /// whether `mod.json` has a main script, as in JavaScript mods
+    #[serde(default)]
+    pub is_js_mod: bool,
+    /// whether the repository is archived, as in no longer maintained
+    #[serde(default)]
+    pub archived: bool,
+    /// primary category, inferred from the contents, assets and main script
+    #[serde(default)]
+    pub category: String,
+    /// maintenance status at the refresh, as in `status::ALL`
+    #[serde(default)]
+    pub status: String,
+    /// `mod.json` dependencies, by mod name
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    /// dependencies which aren't found in the listing
+    #[serde(default)]
+    pub unresolved_dependencies: Vec<String>,
+    /// whether the maintainers hand-picked the mod, in `featured.txt`
+    #[serde(default)]
+    pub featured: bool,
+    /// whether bundles are the only asset, as in translation mods
+    #[serde(default)]
+    pub is_translation: bool,
+    /// locales of the bundles, as in `["en", "pt_BR"]`
+    #[serde(default)]
+    pub locales: Vec<String>,
 }
 
 #[cfg(feature = "pyo3")]
@@ -85,8 +250,25 @@ impl Mod {
         display_name: Option<String>,
         default_branch: String,
         min_game_version: Option<String>,
+        dependencies: Vec<String>,
+        last_commit_msg: Option<String>,
+        changelog: Option<String>,
+        readme_unavailable: bool,
+        license: Option<String>,
+        is_js_mod: bool,
+        size_kb: Option<u32>,
+        activity: Vec<u32>,
+        archived: bool,
+        accent: Option<String>,
+        authors: Vec<String>,
+        bundles: Vec<String>,
+        created: String,
+        created_tt: f64,
     ) -> PyResult<Self> {
-        Ok(Self {
+        let screenshots = screenshots::extract(&readme, &repo, &default_branch);
+        let category = category::infer(&contents, &assets, is_js_mod).to_string();
+        let is_translation = bundles::is_translation(&contents, &assets, is_js_mod);
+        let mut m = Self {
             name,
             name_markup,
             link,
@@ -94,19 +276,44 @@ impl Mod {
             desc,
             desc_markup,
             icon,
+            accent,
             stars,
             author,
+            authors,
             author_markup,
             date,
             date_tt,
+            created,
+            created_tt,
+            last_commit_msg: last_commit_msg.as_deref().map(commit_subject),
             readme,
+            readme_unavailable,
+            license,
+            screenshots,
+            activity,
+            changelog,
             version,
+            size_kb,
             assets,
             contents,
             display_name,
             default_branch,
             min_game_version,
-        })
+            is_js_mod,
+            archived,
+            category,
+            status: String::new(),
+            dependencies,
+            unresolved_dependencies: vec![],
+            featured: false,
+            is_translation,
+            locales: bundles::locales(&bundles),
+        };
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0.0, |x| x.as_secs_f64());
+        m.status = m.status_at(now).to_string();
+        Ok(m)
     }
 
     pub fn date_tt(&self) -> PyResult<f64> {
@@ -114,8 +321,268 @@ impl Mod {
     }
 }
 
+/// Time between two UTC timestamp epochs in seconds, or `None` if `then` is
+/// after `now` or either isn't usable.
+fn since(then: f64, now: f64) -> Option<Duration> {
+    let secs = now - then;
+    if secs >= 0.0 && secs < u64::MAX as f64 {
+        Some(Duration::from_secs_f64(secs))
+    } else {
+        None
+    }
+}
+
 impl Mod {
+    /// Endpoint url query string for mod, which is the repository
+    /// with `/` replaced, as in `"user--repo"`.
+    pub fn endpoint_query(&self) -> String {
+        self.repo.replace("/", "--")
+    }
+
+    /// Name which other mods refer to this mod by, in their `mod.json`
+    /// dependencies.
+    pub fn mindustry_name(&self) -> String {
+        dependencies::normalize(&self.name)
+    }
+
+    /// Whether the repository is owned by one of the `orgs`, as in
+    /// `TRUSTED_ORGS`. GitHub owners are case insensitive.
+    pub fn is_trusted(&self, orgs: &[&str]) -> bool {
+        let owner = self.repo.split('/').next().unwrap_or("");
+        orgs.iter().any(|x| x.eq_ignore_ascii_case(owner))
+    }
+
+    /// Author names, falling back to `author` for data from before
+    /// `authors`.
+    pub fn author_names(&self) -> Vec<&str> {
+        if self.authors.is_empty() {
+            vec![self.author.as_str()]
+        } else {
+            self.authors.iter().map(String::as_str).collect()
+        }
+    }
+
+    /// Whether `author` is one of the authors, compared like mod names.
+    pub fn is_by(&self, author: &str) -> bool {
+        let author = dependencies::normalize(author);
+        self.author_names()
+            .iter()
+            .any(|x| dependencies::normalize(x) == author)
+    }
+
+    /// Whether one of the `mod.json` dependencies refers to the mod `name`.
+    pub fn depends_on(&self, name: &str) -> bool {
+        let name = dependencies::normalize(name);
+        self.dependencies
+            .iter()
+            .any(|dep| dependencies::normalize(dep) == name)
+    }
+
+    /// Whether the description has any text, once stripped of markup.
+    pub fn has_description(&self) -> bool {
+        !markup::strip(&self.desc).trim().is_empty()
+    }
+
+    /// Whether the mod runs on the `target` game version. Mods which don't
+    /// declare a minimum game version are compatible with every version.
+    pub fn matches_game_version(&self, target: &str) -> bool {
+        self.min_game_version
+            .as_deref()
+            .map_or(true, |minimum| game_version::satisfies(minimum, target))
+    }
+
+    /// Time since the last commit, at `now` as a UTC timestamp epoch in
+    /// seconds, or `None` if the commit is in the future or its timestamp
+    /// isn't usable.
+    pub fn age(&self, now: f64) -> Option<Duration> {
+        since(self.date_tt, now)
+    }
+
+    /// Time since the repository was created, at `now` as a UTC timestamp
+    /// epoch in seconds, or `None` if it's unknown, as in data from before
+    /// `created`, or isn't usable.
+    pub fn created_age(&self, now: f64) -> Option<Duration> {
+        if self.created_tt > 0.0 {
+            since(self.created_tt, now)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the last commit is at most `within` old at `now`, as a UTC
+    /// timestamp epoch in seconds.
+    pub fn is_fresh(&self, now: f64, within: Duration) -> bool {
+        self.age(now).map_or(false, |age| age <= within)
+    }
+
+    /// Maintenance status at `now`, as a UTC timestamp epoch in seconds.
+    pub fn status_at(&self, now: f64) -> &'static str {
+        status::infer(self.age(now), self.archived)
+    }
+
+    /// Link to a zip archive of the default branch.
     pub fn archive_link(&self) -> String {
-        format!("https://github.com/{}/archive/master.zip", &self.repo)
+        format!(
+            "https://github.com/{}/archive/{}.zip",
+            &self.repo, &self.default_branch
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn schema_matches_fields() {
+        let schema: serde_json::Value = serde_json::from_str(MOD_SCHEMA).unwrap();
+        let properties = schema["items"]["properties"].as_object().unwrap();
+        let serialized = serde_json::to_value(Mod::default()).unwrap();
+        let fields = serialized.as_object().unwrap();
+        let mut expected: Vec<&String> = properties.keys().collect();
+        let mut actual: Vec<&String> = fields.keys().collect();
+        expected.sort();
+        actual.sort();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let m = Mod {
+            name: "example".into(),
+            repo: "Someone/example".into(),
+            authors: vec!["Someone".into()],
+            display_name: Some("[red]Example".into()),
+            min_game_version: Some("126".into()),
+            activity: vec![1, 2],
+            date_tt: 1.5,
+            archived: true,
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&m).unwrap();
+        assert_eq!(json["display_name"], "[red]Example");
+        let back: Mod = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&back).unwrap(), json);
+    }
+
+    #[test]
+    fn game_version_matching() {
+        let with_min = |v: Option<&str>| Mod {
+            min_game_version: v.map(String::from),
+            ..Default::default()
+        };
+        assert!(with_min(None).matches_game_version("104"));
+        assert!(with_min(Some("105")).matches_game_version("126"));
+        assert!(!with_min(Some("126")).matches_game_version("105"));
+    }
+
+    #[test]
+    fn trusted() {
+        let owned_by = |repo: &str| Mod {
+            repo: repo.into(),
+            ..Default::default()
+        };
+        assert!(owned_by("Anuken/ExampleMod").is_trusted(TRUSTED_ORGS));
+        assert!(owned_by("anuken/ExampleMod").is_trusted(TRUSTED_ORGS));
+        assert!(!owned_by("Someone/ExampleMod").is_trusted(TRUSTED_ORGS));
+        assert!(!owned_by("Someone/Anuken").is_trusted(TRUSTED_ORGS));
+        assert!(owned_by("Someone/ExampleMod").is_trusted(&["Other", "someone"]));
+        assert!(!owned_by("Anuken/ExampleMod").is_trusted(&[]));
+    }
+
+    #[test]
+    fn authors() {
+        let single = Mod {
+            author: "Someone".into(),
+            ..Default::default()
+        };
+        assert_eq!(single.author_names(), vec!["Someone"]);
+        assert!(single.is_by("someone"));
+        assert_eq!(Mod::default().author_names(), vec![""]);
+
+        let several = Mod {
+            author: "Someone, Other Person".into(),
+            authors: vec!["Someone".into(), "Other Person".into()],
+            ..Default::default()
+        };
+        assert_eq!(several.author_names(), vec!["Someone", "Other Person"]);
+        assert!(several.is_by("other-person"));
+        assert!(several.is_by("Someone"));
+        assert!(!several.is_by("Someone, Other Person"));
+    }
+
+    #[test]
+    fn depends_on_normalized() {
+        let m = Mod {
+            dependencies: vec!["Base Lib".into()],
+            ..Default::default()
+        };
+        assert!(m.depends_on("base-lib"));
+        assert!(m.depends_on(" BASE LIB"));
+        assert!(!m.depends_on("base"));
+    }
+
+    #[test]
+    fn description() {
+        let with = |desc: &str| Mod {
+            desc: desc.into(),
+            ..Default::default()
+        };
+        assert!(with("Adds turrets").has_description());
+        assert!(!with("").has_description());
+        assert!(!with("  \n").has_description());
+        assert!(!with("[red][]").has_description());
+    }
+
+    #[test]
+    fn fresh_boundary() {
+        let day = Duration::from_secs(24 * 60 * 60);
+        let m = Mod {
+            date_tt: 1_000_000.0,
+            ..Default::default()
+        };
+        let now = 1_000_000.0 + day.as_secs_f64();
+        assert_eq!(m.age(now), Some(day));
+        assert!(m.is_fresh(now, day));
+        assert!(!m.is_fresh(now + 1.0, day));
+        assert!(m.is_fresh(m.date_tt, Duration::from_secs(0)));
+    }
+
+    #[test]
+    fn fresh_unusable() {
+        let day = Duration::from_secs(24 * 60 * 60);
+        let at = |date_tt: f64| Mod {
+            date_tt,
+            ..Default::default()
+        };
+        assert_eq!(at(2.0).age(1.0), None);
+        assert!(!at(2.0).is_fresh(1.0, day));
+        assert!(!at(f64::NAN).is_fresh(1.0, day));
+        assert_eq!(at(f64::NEG_INFINITY).age(1.0), None);
+    }
+
+    #[test]
+    fn created_age() {
+        let at = |created_tt: f64| Mod {
+            created_tt,
+            ..Default::default()
+        };
+        assert_eq!(at(1.0).created_age(3.0), Some(Duration::from_secs(2)));
+        assert_eq!(at(0.0).created_age(3.0), None);
+        assert_eq!(at(4.0).created_age(3.0), None);
+    }
+
+    #[test]
+    fn commit_subject_first_line() {
+        assert_eq!(commit_subject("Fix icons\n\nLong body."), "Fix icons");
+        assert_eq!(commit_subject("  Trimmed  "), "Trimmed");
+        assert_eq!(commit_subject(""), "");
+    }
+
+    #[test]
+    fn commit_subject_truncated() {
+        let subject = commit_subject(&"a".repeat(100));
+        assert_eq!(subject.chars().count(), COMMIT_SUBJECT_LEN);
+        assert!(subject.ends_with('…'));
     }
 }
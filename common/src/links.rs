@@ -0,0 +1,97 @@
+//! Links of READMEs, which are relative to their repository, made absolute
+//! such that they still work when the README is rendered on the site.
+
+use crate::screenshots;
+
+/// Start of a url, before its path, query or fragment, which has its scheme
+/// if it has one.
+pub fn head(url: &str) -> &str {
+    &url[..url
+        .find(|c| c == '/' || c == '?' || c == '#')
+        .unwrap_or(url.len())]
+}
+
+/// Scheme of a url, as `"https"` of `"https://example.com"`, or `None` if it
+/// has none. Like browsers, a colon which doesn't follow a letter and then
+/// only letters, digits, `+`, `-` or `.`, as in `"images/a:b.png"`, doesn't
+/// make one.
+pub fn scheme(url: &str) -> Option<&str> {
+    let head = head(url);
+    let scheme = &head[..head.find(':')?];
+    let valid = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.');
+    if valid {
+        Some(scheme)
+    } else {
+        None
+    }
+}
+
+/// Whether a url is relative to the repository, rather than absolute, an
+/// anchor within the page, or with a scheme such as `mailto:`.
+pub fn is_relative(url: &str) -> bool {
+    let url = url.trim();
+    !(url.is_empty() || url.starts_with('#') || url.starts_with("//") || scheme(url).is_some())
+}
+
+/// Absolute url of a link of the README of `repo`, leaving urls which aren't
+/// relative as they are. Images point to the raw content, such that they
+/// load, while other links point to the file's page on GitHub.
+pub fn absolute(url: &str, repo: &str, branch: &str, image: bool) -> String {
+    if !is_relative(url) {
+        url.to_string()
+    } else if image {
+        screenshots::resolve(url.trim(), repo, branch)
+    } else {
+        let path = url.trim().trim_start_matches("./").trim_start_matches('/');
+        format!("https://github.com/{}/blob/{}/{}", repo, branch, path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn schemes() {
+        assert_eq!(scheme("https://example.com/a:b"), Some("https"));
+        assert_eq!(scheme("mailto:someone@example.com"), Some("mailto"));
+        assert_eq!(scheme("svn+ssh://example.com"), Some("svn+ssh"));
+        assert_eq!(scheme("images/a:b.png"), None);
+        assert_eq!(scheme("a?b:c"), None);
+        assert_eq!(scheme("1a:b"), None);
+        assert_eq!(scheme(":b"), None);
+    }
+
+    #[test]
+    fn relative() {
+        assert!(is_relative("docs/guide.md"));
+        assert!(is_relative("./icon.png"));
+        assert!(is_relative("/sprites/a.png"));
+        assert!(!is_relative("a:b/c.png"));
+        assert!(is_relative("images/a:b.png"));
+        assert!(!is_relative("#install"));
+        assert!(!is_relative("https://example.com/a.png"));
+        assert!(!is_relative("//example.com/a.png"));
+        assert!(!is_relative("mailto:someone@example.com"));
+    }
+
+    #[test]
+    fn absolutes() {
+        assert_eq!(
+            absolute("docs/guide.md#setup", "user/repo", "main", false),
+            "https://github.com/user/repo/blob/main/docs/guide.md#setup"
+        );
+        assert_eq!(
+            absolute("./preview.png", "user/repo", "main", true),
+            "https://raw.githubusercontent.com/user/repo/main/preview.png"
+        );
+        assert_eq!(absolute("#install", "user/repo", "main", false), "#install");
+        assert_eq!(
+            absolute("https://i.imgur.com/a.png", "user/repo", "main", true),
+            "https://i.imgur.com/a.png"
+        );
+    }
+}
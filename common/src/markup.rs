@@ -8,6 +8,8 @@ use nom::{
     IResult,
 };
 
+use crate::color::Color;
+
 type PResult<'a, T> = IResult<&'a str, T>;
 
 /// Represents a color markup token, for the purposes of
@@ -79,13 +81,31 @@ fn hex_primary(input: &str) -> PResult<u8> {
     map_res(take_while_m_n(2, 2, is_hex_digit), from_hex)(input)
 }
 
-fn hex_color(input: &str) -> PResult<Markup> {
-    let (input, (r, g, b)) =
-        preceded(char('#'), tuple((hex_primary, hex_primary, hex_primary)))(input)?;
+/// Single digit shorthand of a primary, where `f` expands to `ff`.
+fn hex_short_primary(input: &str) -> PResult<u8> {
+    map_res(take_while_m_n(1, 1, is_hex_digit), |x: &str| {
+        from_hex(x).map(|x| x * 0x11)
+    })(input)
+}
+
+/// Parsed `#rrggbb(aa)` long form.
+fn hex_long(input: &str) -> PResult<Markup> {
+    let (input, (r, g, b)) = tuple((hex_primary, hex_primary, hex_primary))(input)?;
     let (input, a) = opt(hex_primary)(input)?;
     Ok((input, Markup::HexColor { r, g, b, a }))
 }
 
+/// Parsed `#rgb` short form.
+fn hex_short(input: &str) -> PResult<Markup> {
+    let (input, (r, g, b)) =
+        tuple((hex_short_primary, hex_short_primary, hex_short_primary))(input)?;
+    Ok((input, Markup::HexColor { r, g, b, a: None }))
+}
+
+fn hex_color(input: &str) -> PResult<Markup> {
+    preceded(char('#'), alt((hex_long, hex_short)))(input)
+}
+
 fn named_color(input: &str) -> PResult<Markup> {
     let (input, color) = alpha1(input)?;
     Ok((input, Markup::Named(color)))
@@ -104,6 +124,18 @@ fn color_markup(input: &str) -> PResult<Markup> {
 }
 
 impl Markup<'_> {
+    /// Color which the tag changes to, if it's a color tag.
+    pub fn color(&self) -> Option<Color> {
+        match *self {
+            Self::HexColor { r, g, b, a } => Some(match a {
+                Some(a) => [r, g, b, a].into(),
+                None => [r, g, b].into(),
+            }),
+            Self::Named(name) => Some(name.into()),
+            _ => None,
+        }
+    }
+
     pub fn from_str(input: &str) -> PResult<Vec<Markup>> {
         many0(alt((
             color_markup,
@@ -113,6 +145,36 @@ impl Markup<'_> {
     }
 }
 
+/// Parses markup, where input which fails to parse is kept as visible text
+/// instead of being dropped. The unparsed input is also returned, if there's
+/// any, so that it can be reported.
+pub fn from_str_lossy(input: &str) -> (Vec<Markup>, Option<&str>) {
+    match Markup::from_str(input) {
+        Ok(("", tokens)) => (tokens, None),
+        Ok((rest, mut tokens)) => {
+            tokens.push(Markup::Text(rest));
+            (tokens, Some(rest))
+        }
+        Err(_) => (vec![Markup::Text(input)], Some(input)),
+    }
+}
+
+/// Returns the visible text of a markup string, without any color tags.
+/// Input which can't be parsed is kept as is.
+pub fn strip(input: &str) -> String {
+    let (tokens, _) = from_str_lossy(input);
+    let mut output = String::with_capacity(input.len());
+    for token in tokens {
+        match token {
+            Markup::Text(text) => output.push_str(text),
+            Markup::Escaped => output.push('['),
+            Markup::NewLine => output.push('\n'),
+            Markup::HexColor { .. } | Markup::Named(_) | Markup::Popped => {}
+        }
+    }
+    output
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -128,6 +190,22 @@ mod test {
             assert_eq!(hex_color("#2F14DF05"), Ok(("", [47, 20, 223, 5].into())));
             assert_eq!(named_color("red"), Ok(("", Markup::Named("red"))));
         }
+
+        #[test]
+        fn short() {
+            assert_eq!(hex_color("#f00"), Ok(("", [255, 0, 0].into())));
+            assert_eq!(hex_color("#F0a"), Ok(("", [255, 0, 170].into())));
+            assert_eq!(color_markup("[#f00]"), Ok(("", [255, 0, 0].into())));
+            assert!(color_markup("[#ff00]").is_err());
+        }
+
+        #[test]
+        fn equivalent() {
+            let color = |input| Markup::from_str(input).unwrap().1[0].color().unwrap();
+            assert_eq!(color("[#f00]"), color("[#ff0000]"));
+            assert_eq!(color("[#f00]").to_string(), "#ff0000");
+            assert_eq!(color("[red]").rgb(), color("[#f00]").rgb());
+        }
     }
 
     mod color_markup {
@@ -191,4 +269,51 @@ mod test {
             );
         }
     }
+
+    mod lossy {
+        use super::*;
+        use Markup::*;
+
+        #[test]
+        fn broken() {
+            assert_eq!(
+                from_str_lossy("[red]text[oops"),
+                (
+                    vec![Named("red"), Text("text"), Text("[oops")],
+                    Some("[oops")
+                )
+            );
+            assert_eq!(
+                from_str_lossy("[#12]text"),
+                (vec![Text("[#12]text")], Some("[#12]text"))
+            );
+        }
+
+        #[test]
+        fn valid() {
+            assert_eq!(
+                from_str_lossy("[red]text"),
+                (vec![Named("red"), Text("text")], None)
+            );
+        }
+    }
+
+    mod strip {
+        use super::*;
+
+        #[test]
+        fn authors() {
+            assert_eq!(strip("[orange]What42Pizza"), "What42Pizza");
+            assert_eq!(strip("What42Pizza[]"), "What42Pizza");
+            assert_eq!(strip("What42Pizza[red]"), "What42Pizza");
+            assert_eq!(strip("[red]What[#ff0000]42[]Pizza[]"), "What42Pizza");
+            assert_eq!(strip("[[What42Pizza"), "[What42Pizza");
+            assert_eq!(strip("What42Pizza"), "What42Pizza");
+        }
+
+        #[test]
+        fn unclosed() {
+            assert_eq!(strip("[red]What42Pizza [unclosed"), "What42Pizza [unclosed");
+        }
+    }
 }
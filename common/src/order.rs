@@ -0,0 +1,257 @@
+//! Orders of the listing, newest, most starred or trending first. Ties are broken by
+//! repository, such that mods with equal keys keep the same order between
+//! renders instead of depending on the order they came in.
+
+use crate::{search, Mod};
+use std::cmp::Ordering;
+
+/// Names of the orders, as in the `sort` url parameter.
+pub const COMMIT: &str = "commit";
+pub const STARS: &str = "stars";
+pub const CREATED: &str = "newest";
+pub const TRENDING: &str = "trending";
+pub const RELEVANCE: &str = "relevance";
+
+/// Every order name.
+pub const ALL: [&str; 5] = [COMMIT, STARS, CREATED, TRENDING, RELEVANCE];
+
+/// Order to list by: the one of the url, such that shared links list the
+/// same way, or else the stored one, which is the user's last choice, or else
+/// `COMMIT`. Unknown names are skipped.
+pub fn preferred(url: Option<&str>, stored: Option<&str>) -> &'static str {
+    [url, stored]
+        .iter()
+        .flatten()
+        .find_map(|name| ALL.iter().find(|&x| x == name))
+        .unwrap_or(&COMMIT)
+}
+
+/// Weights of a search term found in the name, the author, the description
+/// and the README of a mod, in its relevance.
+const RELEVANCE_WEIGHTS: [u32; 4] = [8, 4, 2, 1];
+
+/// Relevance of a mod to a search query: the sum, over its terms, of the
+/// `RELEVANCE_WEIGHTS` of the fields each is found in, ignoring case, such
+/// that matches in the name count most. Terms are parsed as in
+/// `search::parse`.
+pub fn relevance(m: &Mod, query: &str) -> u32 {
+    let query = query.to_lowercase();
+    let fields = [&m.name, &m.author, &m.desc, &m.readme];
+    let fields: Vec<String> = fields.iter().map(|x| x.to_lowercase()).collect();
+    search::parse(&query)
+        .into_iter()
+        .flatten()
+        .map(|term| {
+            fields
+                .iter()
+                .zip(RELEVANCE_WEIGHTS.iter())
+                .filter(|(field, _)| field.contains(term))
+                .map(|(_, weight)| weight)
+                .sum::<u32>()
+        })
+        .sum()
+}
+
+/// Days after which the stars of a mod count half in its trending score.
+pub const TRENDING_HALF_LIFE: f64 = 30.0;
+
+/// Total order of floats, where `NaN`, which can't be compared, is the
+/// lowest. Casting timestamps to an integer instead would saturate or lose
+/// precision.
+fn total_cmp(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    }
+}
+
+/// Most recent commit first.
+pub fn by_commit(a: &Mod, b: &Mod) -> Ordering {
+    total_cmp(b.date_tt, a.date_tt).then_with(|| a.repo.cmp(&b.repo))
+}
+
+/// Most recently created repository first, and those whose creation is
+/// unknown last.
+pub fn by_created(a: &Mod, b: &Mod) -> Ordering {
+    total_cmp(b.created_tt, a.created_tt).then_with(|| a.repo.cmp(&b.repo))
+}
+
+/// Most stars first.
+pub fn by_stars(a: &Mod, b: &Mod) -> Ordering {
+    b.stars.cmp(&a.stars).then_with(|| a.repo.cmp(&b.repo))
+}
+
+/// Trending score at `now`, a UTC timestamp epoch in seconds: the stars
+/// decayed by half every `TRENDING_HALF_LIFE` days since the last commit,
+///
+/// ```text
+/// (stars + 1) * 0.5 ^ (age in days / TRENDING_HALF_LIFE)
+/// ```
+///
+/// such that 100 stars committed to two months ago score as 25 stars today,
+/// and mods without stars still rank by recency. Commits in the future, as
+/// with clock skew, count as made `now`.
+pub fn trending_score(m: &Mod, now: f64) -> f64 {
+    const DAY: f64 = 24.0 * 60.0 * 60.0;
+    if !m.date_tt.is_finite() {
+        return 0.0;
+    }
+    let days = ((now - m.date_tt) / DAY).max(0.0);
+    (f64::from(m.stars) + 1.0) * 0.5f64.powf(days / TRENDING_HALF_LIFE)
+}
+
+/// Highest trending score at `now` first.
+pub fn by_trending(a: &Mod, b: &Mod, now: f64) -> Ordering {
+    total_cmp(trending_score(b, now), trending_score(a, now)).then_with(|| a.repo.cmp(&b.repo))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example(repo: &str, stars: u32, date_tt: f64) -> Mod {
+        Mod {
+            repo: repo.into(),
+            stars,
+            date_tt,
+            ..Default::default()
+        }
+    }
+
+    fn sorted(mut mods: Vec<Mod>, order: fn(&Mod, &Mod) -> Ordering) -> Vec<String> {
+        mods.sort_by(order);
+        mods.into_iter().map(|x| x.repo).collect()
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(preferred(Some(STARS), Some(TRENDING)), STARS);
+        assert_eq!(preferred(None, Some(TRENDING)), TRENDING);
+        assert_eq!(preferred(None, None), COMMIT);
+        assert_eq!(preferred(Some("bogus"), Some(CREATED)), CREATED);
+        assert_eq!(preferred(Some("bogus"), Some("bogus")), COMMIT);
+    }
+
+    #[test]
+    fn relevance_weights() {
+        let with = |name: &str, author: &str, desc: &str, readme: &str| Mod {
+            name: name.into(),
+            author: author.into(),
+            desc: desc.into(),
+            readme: readme.into(),
+            ..Default::default()
+        };
+        let in_name = with("Turrets", "", "", "");
+        let in_readme = with("Other", "", "", "Adds turrets");
+        let everywhere = with("Turrets", "Turret maker", "turrets", "turrets");
+        assert!(relevance(&in_name, "turret") > relevance(&in_readme, "turret"));
+        assert_eq!(relevance(&everywhere, "TURRET"), 15);
+        assert_eq!(relevance(&in_name, "turret | cannon"), 8);
+        assert_eq!(relevance(&in_readme, "turret adds"), 2);
+        assert_eq!(relevance(&in_name, ""), 0);
+    }
+
+    #[test]
+    fn stars() {
+        let mods = vec![
+            example("b/tied", 3, 0.0),
+            example("c/most", 9, 0.0),
+            example("a/tied", 3, 0.0),
+        ];
+        assert_eq!(sorted(mods, by_stars), vec!["c/most", "a/tied", "b/tied"]);
+    }
+
+    #[test]
+    fn commit() {
+        let mods = vec![
+            example("b/tied", 0, 1.0),
+            example("a/tied", 0, 1.0),
+            example("c/newest", 0, 2.0),
+        ];
+        assert_eq!(
+            sorted(mods, by_commit),
+            vec!["c/newest", "a/tied", "b/tied"]
+        );
+    }
+
+    #[test]
+    fn created() {
+        let at = |repo: &str, created_tt: f64| Mod {
+            repo: repo.into(),
+            created_tt,
+            ..Default::default()
+        };
+        let mods = vec![at("a/unknown", 0.0), at("b/old", 1.0), at("c/new", 2.0)];
+        assert_eq!(
+            sorted(mods, by_created),
+            vec!["c/new", "b/old", "a/unknown"]
+        );
+    }
+
+    #[test]
+    fn commit_precision() {
+        let mods = vec![
+            example("a/nan", 0, f64::NAN),
+            example("b/before", 0, -1.0),
+            example("c/fraction", 0, 1.5),
+            example("d/second", 0, 1.0),
+            example("e/beyond", 0, 5e9),
+        ];
+        let expected = vec!["e/beyond", "c/fraction", "d/second", "b/before", "a/nan"];
+        assert_eq!(sorted(mods, by_commit), expected);
+    }
+
+    #[test]
+    fn trending_decay() {
+        const DAY: f64 = 24.0 * 60.0 * 60.0;
+        let now = 1000.0 * DAY;
+        let score = |stars, days_ago: f64| {
+            trending_score(&example("a/b", stars, now - days_ago * DAY), now)
+        };
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        assert!(close(score(99, 0.0), 100.0));
+        assert!(close(score(99, 60.0), 25.0));
+        assert!(close(score(99, -1.0), 100.0));
+        assert!(close(
+            trending_score(&example("a/b", 99, f64::NAN), now),
+            0.0
+        ));
+    }
+
+    #[test]
+    fn trending() {
+        const DAY: f64 = 24.0 * 60.0 * 60.0;
+        let now = 1000.0 * DAY;
+        let mods = vec![
+            example("a/old-popular", 400, now - 365.0 * DAY),
+            example("b/new-obscure", 0, now),
+            example("c/active-popular", 100, now - 7.0 * DAY),
+        ];
+        let mut sorted = mods;
+        sorted.sort_by(|a, b| by_trending(a, b, now));
+        let repos: Vec<&str> = sorted.iter().map(|x| x.repo.as_str()).collect();
+        assert_eq!(
+            repos,
+            vec!["c/active-popular", "b/new-obscure", "a/old-popular"]
+        );
+    }
+
+    #[test]
+    fn stable_ties() {
+        let mods = vec![
+            example("x/one", 1, 5.0),
+            example("y/two", 1, 5.0),
+            example("z/three", 1, 5.0),
+        ];
+        let mut reversed = mods.clone();
+        reversed.reverse();
+        for order in &[by_stars, by_commit] {
+            assert_eq!(
+                sorted(mods.clone(), *order),
+                sorted(reversed.clone(), *order)
+            );
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! Allowlist sanitizing of the HTML in READMEs, which anyone can write, such
+//! that they can't inject scripts, styles or event handlers into the site.
+
+use crate::links;
+
+/// Tags kept, without any attribute but `ATTRIBUTES`.
+#[rustfmt::skip]
+const TAGS: &[&str] = &[
+    "a", "abbr", "b", "blockquote", "br", "center", "code", "dd", "del", "details", "div", "dl",
+    "dt", "em", "h1", "h2", "h3", "h4", "h5", "h6", "hr", "i", "img", "ins", "kbd", "li", "ol",
+    "p", "pre", "s", "samp", "span", "strike", "strong", "sub", "summary", "sup", "table",
+    "tbody", "td", "tfoot", "th", "thead", "tr", "tt", "u", "ul",
+];
+
+/// Tags dropped along with their content, rather than only the tag.
+const DROPPED: &[&str] = &[
+    "script", "style", "iframe", "object", "embed", "noscript", "noembed", "noframes", "template",
+    "textarea", "title", "xmp",
+];
+
+/// Attributes kept on the `TAGS`.
+const ATTRIBUTES: &[&str] = &[
+    "align", "alt", "colspan", "height", "href", "open", "rowspan", "src", "title", "width",
+];
+
+/// Attributes which are urls, kept only if `is_safe_url`.
+const URL_ATTRIBUTES: &[&str] = &["href", "src"];
+
+/// Url schemes allowed in links and images.
+const SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/// Whether a url is relative, or has one of the `SCHEMES`. Whitespace and
+/// control characters are ignored like browsers do, and character references
+/// before the path are refused, since browsers decode them in attributes.
+pub fn is_safe_url(url: &str) -> bool {
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_lowercase();
+    if links::head(&url).contains('&') {
+        return false;
+    }
+    match links::scheme(&url) {
+        Some(scheme) => SCHEMES.contains(&scheme),
+        None => true,
+    }
+}
+
+/// Escapes an attribute value for double quotes, leaving the character
+/// references of urls as they are.
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Index of the `>` closing a tag which starts at `tag[0]`, ignoring the ones
+/// in quoted attribute values.
+fn tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in tag.char_indices().skip(1) {
+        match (quote, c) {
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Allowed attributes of the inside of a `tag`, after its name, as
+/// ` name="value"` pairs, with the urls passed through `urls`.
+fn attributes(tag: &str, mut rest: &str, urls: &dyn Fn(&str, &str) -> String) -> String {
+    let mut output = String::new();
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+        let name_len = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        if name_len == 0 {
+            return output;
+        }
+        let name = rest[..name_len].to_lowercase();
+        rest = rest[name_len..].trim_start();
+
+        let mut value = "";
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            let (v, r) = match after.chars().next() {
+                Some(q) if q == '"' || q == '\'' => {
+                    let end = after[1..].find(q).map_or(after.len(), |x| x + 1);
+                    (&after[1..end], after.get(end + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    after.split_at(end)
+                }
+            };
+            value = v;
+            rest = r;
+        }
+
+        if !ATTRIBUTES.contains(&name.as_str()) {
+            continue;
+        }
+        if URL_ATTRIBUTES.contains(&name.as_str()) {
+            if is_safe_url(value) {
+                let url = urls(tag, value);
+                output.push_str(&format!(" {}=\"{}\"", name, escape_attribute(&url)));
+            }
+        } else {
+            output.push_str(&format!(" {}=\"{}\"", name, escape_attribute(value)));
+        }
+    }
+}
+
+/// Sanitizes html, keeping only the `TAGS` with their `ATTRIBUTES`, and text.
+/// Other tags are removed, and the `DROPPED` ones along with their content,
+/// as are comments. A `<` which doesn't start a tag is escaped.
+pub fn html(input: &str) -> String {
+    html_with_urls(input, &|_, url| url.to_string())
+}
+
+/// Sanitizes html like `html`, with the kept urls passed through `urls`,
+/// along with the name of their tag, as in `urls("img", "icon.png")`.
+pub fn html_with_urls(input: &str, urls: &dyn Fn(&str, &str) -> String) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find('<') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |x| &rest[x + 3..]);
+            continue;
+        }
+        // as in `<!doctype html>`, or `<?xml version="1.0"?>`
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |x| &rest[x + 1..]);
+            continue;
+        }
+        let closing = rest.starts_with("</");
+        let name_start = if closing { 2 } else { 1 };
+        let starts_tag = rest[name_start..].starts_with(|c: char| c.is_ascii_alphabetic());
+        let end = match tag_end(rest) {
+            Some(end) if starts_tag => end,
+            _ => {
+                output.push_str("&lt;");
+                rest = &rest[1..];
+                continue;
+            }
+        };
+        let inside = &rest[name_start..end];
+        rest = &rest[end + 1..];
+
+        let name_len = inside
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(inside.len());
+        let name = inside[..name_len].to_ascii_lowercase();
+
+        if DROPPED.contains(&name.as_str()) {
+            if !closing {
+                let close = format!("</{}", name);
+                rest = match rest.to_ascii_lowercase().find(&close) {
+                    Some(x) => tag_end(&rest[x..]).map_or("", |end| &rest[x + end + 1..]),
+                    None => "",
+                };
+            }
+        } else if TAGS.contains(&name.as_str()) {
+            if closing {
+                output.push_str(&format!("</{}>", name));
+            } else {
+                output.push_str(&format!(
+                    "<{}{}>",
+                    name,
+                    attributes(&name, &inside[name_len..], urls)
+                ));
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scripts() {
+        assert_eq!(html("a<script>alert(1)</script>b"), "ab");
+        assert_eq!(html("a<SCRIPT src=x></SCRIPT >b"), "ab");
+        assert_eq!(html("a<script>alert(1)"), "a");
+        assert_eq!(html("<style>body { display: none }</style>"), "");
+        assert_eq!(html("<svg><script>alert(1)</script></svg>"), "");
+        assert_eq!(html("<iframe src=\"https://example.com\"></iframe>"), "");
+    }
+
+    #[test]
+    fn event_handlers() {
+        assert_eq!(
+            html("<img src=x.png onerror=alert(1)>"),
+            "<img src=\"x.png\">"
+        );
+        assert_eq!(
+            html("<img src=\"x.png\" ONERROR='alert(1)' alt=\"a > b\">"),
+            "<img src=\"x.png\" alt=\"a &gt; b\">"
+        );
+        assert_eq!(html("<div onclick=\"alert(1)\" style=\"x\">"), "<div>");
+        assert_eq!(html("<a/onmouseover=alert(1)>x</a>"), "<a>x</a>");
+    }
+
+    #[test]
+    fn urls() {
+        assert_eq!(html("<a href=\"javascript:alert(1)\">x</a>"), "<a>x</a>");
+        assert_eq!(html("<a href=\" JaVa\tScRiPt:alert(1)\">x</a>"), "<a>x</a>");
+        assert_eq!(
+            html("<a href=\"java&#115;cript:alert(1)\">x</a>"),
+            "<a>x</a>"
+        );
+        assert_eq!(html("<img src=\"data:image/svg+xml,x\">"), "<img>");
+        assert_eq!(
+            html("<a href=\"https://example.com/?a=1&amp;b=2\">x</a>"),
+            "<a href=\"https://example.com/?a=1&amp;b=2\">x</a>"
+        );
+        assert!(is_safe_url("docs/guide.md"));
+        assert!(is_safe_url("#install"));
+        assert!(is_safe_url("mailto:someone@example.com"));
+        assert!(!is_safe_url("vbscript:x"));
+    }
+
+    #[test]
+    fn rewriting_urls() {
+        let urls = |tag: &str, url: &str| format!("{}:{}", tag, url);
+        assert_eq!(
+            html_with_urls("<a href=\"x\" title=\"x\"><img src=\"y\"></a>", &urls),
+            "<a href=\"a:x\" title=\"x\"><img src=\"img:y\"></a>"
+        );
+        assert_eq!(html_with_urls("<img src=\"javascript:x\">", &urls), "<img>");
+    }
+
+    #[test]
+    fn markup() {
+        let readme = "<p align=\"center\">\n  <img src=\"icon.png\" width=\"64\">\n</p>";
+        assert_eq!(html(readme), readme);
+        assert_eq!(
+            html("<details open><summary>x</summary></details>"),
+            "<details open=\"\"><summary>x</summary></details>"
+        );
+        assert_eq!(html("a < b, <!-- hidden --> c"), "a &lt; b,  c");
+        assert_eq!(html("<blink>x</blink>"), "x");
+        assert_eq!(html("<!DOCTYPE html>x"), "x");
+    }
+}
@@ -0,0 +1,155 @@
+//! Screenshots of a mod, as the images embedded in its README, both in the
+//! markdown `![alt](url)` and the html `<img src="url">` forms.
+
+/// Maximum number of screenshots kept per mod.
+const MAX_SCREENSHOTS: usize = 12;
+
+/// Urls of the markdown `![alt](url "title")` images.
+fn markdown_images(readme: &str) -> Vec<&str> {
+    let mut urls = vec![];
+    let mut rest = readme;
+    while let Some(start) = rest.find("![") {
+        rest = &rest[start + 2..];
+        let close = match rest.find("](") {
+            Some(close) => close,
+            None => break,
+        };
+        let after = &rest[close + 2..];
+        if let Some(end) = after.find(')') {
+            let target = after[..end].trim();
+            let url = if let Some(inner) = target.strip_prefix('<') {
+                inner.split('>').next().unwrap_or("")
+            } else {
+                target.split_whitespace().next().unwrap_or("")
+            };
+            urls.push(url);
+        }
+        rest = after;
+    }
+    urls
+}
+
+/// Urls of the html `<img src="url">` images.
+fn html_images(readme: &str) -> Vec<&str> {
+    let mut urls = vec![];
+    let lower = readme.to_ascii_lowercase();
+    let mut offset = 0;
+    while let Some(start) = lower[offset..].find("<img") {
+        let tag_start = offset + start;
+        let tag_end = lower[tag_start..]
+            .find('>')
+            .map_or(lower.len(), |x| tag_start + x);
+        if let Some(src) = lower[tag_start..tag_end].find("src=") {
+            let value = &readme[tag_start + src + 4..tag_end];
+            let url = match value.chars().next() {
+                Some(quote) if quote == '"' || quote == '\'' => {
+                    value[1..].split(quote).next().unwrap_or("")
+                }
+                _ => value
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or("")
+                    .trim_end_matches('/'),
+            };
+            urls.push(url);
+        }
+        offset = tag_end;
+    }
+    urls
+}
+
+/// Whether the url is a badge, rather than a screenshot.
+fn is_badge(url: &str) -> bool {
+    url.contains("shields.io") || url.contains("badge") || url.ends_with(".svg")
+}
+
+/// Resolves an image url against the raw content of the repository, such
+/// that relative paths and GitHub `blob` links load from anywhere.
+pub fn resolve(url: &str, repo: &str, branch: &str) -> String {
+    let blob = format!("https://github.com/{}/blob/", repo);
+    if url.starts_with(&blob) {
+        format!(
+            "https://raw.githubusercontent.com/{}/{}",
+            repo,
+            &url[blob.len()..]
+        )
+    } else if url.starts_with("http://") || url.starts_with("https://") || url.starts_with("//") {
+        url.to_string()
+    } else {
+        let path = url.trim_start_matches("./").trim_start_matches('/');
+        format!(
+            "https://raw.githubusercontent.com/{}/{}/{}",
+            repo, branch, path
+        )
+    }
+}
+
+/// Returns the absolute urls of the screenshots of a README, in order,
+/// without duplicates or badges.
+pub fn extract(readme: &str, repo: &str, branch: &str) -> Vec<String> {
+    let mut images = markdown_images(readme);
+    images.extend(html_images(readme));
+    let mut urls: Vec<String> = vec![];
+    for url in images {
+        if url.is_empty() || url.starts_with("data:") || is_badge(url) {
+            continue;
+        }
+        let url = resolve(url, repo, branch);
+        if !urls.contains(&url) {
+            urls.push(url);
+        }
+    }
+    urls.truncate(MAX_SCREENSHOTS);
+    urls
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RAW: &str = "https://raw.githubusercontent.com/user/repo";
+
+    #[test]
+    fn markdown() {
+        let readme = "# Mod\n![preview](preview.png) and ![x](<./a b.png> \"title\")";
+        assert_eq!(markdown_images(readme), vec!["preview.png", "./a b.png"]);
+    }
+
+    #[test]
+    fn html() {
+        let readme = r#"<p><IMG width=200 src="img/one.png"/><img src='two.gif'></p><img src=img/three.jpg/>"#;
+        assert_eq!(
+            html_images(readme),
+            vec!["img/one.png", "two.gif", "img/three.jpg"]
+        );
+    }
+
+    #[test]
+    fn resolving() {
+        assert_eq!(
+            resolve("/sprites/a.png", "user/repo", "main"),
+            format!("{}/main/sprites/a.png", RAW)
+        );
+        assert_eq!(
+            resolve(
+                "https://github.com/user/repo/blob/master/a.png",
+                "user/repo",
+                "main"
+            ),
+            format!("{}/master/a.png", RAW)
+        );
+        assert_eq!(
+            resolve("https://i.imgur.com/a.png", "user/repo", "main"),
+            "https://i.imgur.com/a.png"
+        );
+    }
+
+    #[test]
+    fn extracting() {
+        let readme = "![](https://img.shields.io/badge/x.svg) ![](a.png)\n<img src=\"a.png\">";
+        assert_eq!(
+            extract(readme, "user/repo", "master"),
+            vec![format!("{}/master/a.png", RAW)]
+        );
+    }
+}
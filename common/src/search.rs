@@ -0,0 +1,77 @@
+//! Search queries of the listing, where spaces mean AND and `|` means OR, as
+//! in `turret | cannon` for mods mentioning either, or `laser turret | cannon`
+//! for mods mentioning lasers, along with turrets or cannons.
+
+/// Separator of the alternative terms of a group.
+pub const OR: char = '|';
+
+/// Groups of a query, which must all match, of alternative terms, of which
+/// any must match. `|` binds tighter than spaces, and needs no spaces around
+/// it. Empty alternatives, as in `a |`, are left out.
+pub fn parse(query: &str) -> Vec<Vec<&str>> {
+    let mut groups: Vec<Vec<&str>> = vec![];
+    let mut or = false;
+    for word in query.split_whitespace() {
+        for (i, term) in word.split(OR).enumerate() {
+            or |= i > 0;
+            if term.is_empty() {
+                continue;
+            }
+            match groups.last_mut() {
+                Some(group) if or => group.push(term),
+                _ => groups.push(vec![term]),
+            }
+            or = false;
+        }
+    }
+    groups
+}
+
+/// Whether every group of a query has a term for which `matches` is true.
+/// Queries without terms match anything.
+pub fn matches(query: &str, matches: impl Fn(&str) -> bool) -> bool {
+    parse(query)
+        .iter()
+        .all(|group| group.iter().any(|&term| matches(term)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Whether a query matches a text of space separated words.
+    fn matches_words(query: &str, text: &str) -> bool {
+        matches(query, |term| text.split(' ').any(|x| x == term))
+    }
+
+    #[test]
+    fn and() {
+        assert_eq!(parse("a b"), vec![vec!["a"], vec!["b"]]);
+        assert!(matches_words("a b", "a b"));
+        assert!(!matches_words("a b", "a"));
+    }
+
+    #[test]
+    fn or() {
+        assert_eq!(parse("a | b"), vec![vec!["a", "b"]]);
+        assert_eq!(parse("a|b"), parse("a | b"));
+        assert!(matches_words("a | b", "b"));
+        assert!(!matches_words("a | b", "c"));
+    }
+
+    #[test]
+    fn and_of_or() {
+        assert_eq!(parse("a b | c"), vec![vec!["a"], vec!["b", "c"]]);
+        assert!(matches_words("a b | c", "a c"));
+        assert!(matches_words("a b | c", "a b"));
+        assert!(!matches_words("a b | c", "b c"));
+    }
+
+    #[test]
+    fn dangling() {
+        assert_eq!(parse("| a |"), vec![vec!["a"]]);
+        assert_eq!(parse(" | "), Vec::<Vec<&str>>::new());
+        assert!(matches_words("", "a"));
+        assert!(matches_words("|", "a"));
+    }
+}
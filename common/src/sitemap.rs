@@ -0,0 +1,77 @@
+//! Sitemap of every mod overview page, so the listing is visible to search
+//! engines beyond the root page. See: https://www.sitemaps.org/protocol.html
+
+use crate::Mod;
+
+/// Escapes the five XML special characters.
+pub fn escape(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&apos;"),
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+/// Formats a UTC timestamp epoch in seconds as a W3C date (`YYYY-MM-DD`).
+fn w3c_date(tt: f64) -> String {
+    // days to civil date, from: http://howardhinnant.github.io/date_algorithms.html
+    let z = (tt / 86_400.0).floor() as i64 + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+/// Returns a sitemap with one `?mod=<endpoint_query>` url per mod, relative to
+/// the `base_url` of the website.
+pub fn sitemap(base_url: &str, mods: &[Mod]) -> String {
+    let base_url = base_url.trim_end_matches('/');
+    let mut xml = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        "\n",
+        r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#,
+        "\n",
+    ));
+    xml.push_str(&format!("  <url><loc>{}/</loc></url>\n", escape(base_url)));
+    for m in mods {
+        let loc = format!("{}/?mod={}", base_url, m.endpoint_query());
+        xml.push_str(&format!(
+            "  <url><loc>{}</loc><lastmod>{}</lastmod></url>\n",
+            escape(&loc),
+            w3c_date(m.date_tt)
+        ));
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn escaping() {
+        assert_eq!(escape("a&b"), "a&amp;b");
+        assert_eq!(escape("<'\">"), "&lt;&apos;&quot;&gt;");
+        assert_eq!(escape("user--repo"), "user--repo");
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(w3c_date(0.0), "1970-01-01");
+        assert_eq!(w3c_date(951_782_400.0), "2000-02-29");
+        assert_eq!(w3c_date(1_584_549_329.0), "2020-03-18");
+    }
+}
@@ -0,0 +1,70 @@
+//! Split output of the mod listing, with one json file per mod keyed by its
+//! `endpoint_query`, and a lightweight index of every mod. This keeps the
+//! files of unchanged mods identical between updates, and allows fetching
+//! a single mod for its overview.
+
+use crate::Mod;
+use serde::{Deserialize, Serialize};
+
+/// Entry of the index, with just enough to find and order mods.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct IndexEntry {
+    /// file stem of the mod json, as in `"user--repo"`
+    pub endpoint: String,
+    pub name: String,
+    pub repo: String,
+    pub stars: u32,
+    /// last commit UTC timestamp epoch in seconds
+    pub date_tt: f64,
+}
+
+impl From<&Mod> for IndexEntry {
+    fn from(m: &Mod) -> Self {
+        Self {
+            endpoint: m.endpoint_query(),
+            name: m.name.clone(),
+            repo: m.repo.clone(),
+            stars: m.stars,
+            date_tt: m.date_tt,
+        }
+    }
+}
+
+/// Returns the index json, and the `(endpoint, json)` of every mod.
+pub fn split(mods: &[Mod]) -> (String, Vec<(String, String)>) {
+    let index: Vec<IndexEntry> = mods.iter().map(IndexEntry::from).collect();
+    let files = mods
+        .iter()
+        .map(|m| (m.endpoint_query(), serde_json::to_string(m).unwrap()))
+        .collect();
+    (serde_json::to_string(&index).unwrap(), files)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example(repo: &str) -> Mod {
+        Mod {
+            name: repo.split('/').last().unwrap().into(),
+            repo: repo.into(),
+            stars: 3,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn one_file_per_mod() {
+        let mods = vec![example("a/one"), example("b/two")];
+        let (index, files) = split(&mods);
+
+        let index: Vec<IndexEntry> = serde_json::from_str(&index).unwrap();
+        assert_eq!(index.len(), 2);
+        assert_eq!(index[1].endpoint, "b--two");
+        assert_eq!(index[1].name, "two");
+
+        assert_eq!(files[0].0, "a--one");
+        let first: Mod = serde_json::from_str(&files[0].1).unwrap();
+        assert_eq!(first.repo, "a/one");
+    }
+}
@@ -0,0 +1,66 @@
+//! Aggregate stats over the listing, for an overview of the modding
+//! ecosystem.
+
+use crate::Mod;
+use std::collections::HashMap;
+
+/// Number of mods with each content and asset, as in `("blocks", 120)`, most
+/// common first, and then by name.
+pub fn tag_counts<'a>(mods: impl IntoIterator<Item = &'a Mod>) -> Vec<(String, usize)> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for m in mods {
+        let mut tags: Vec<&str> = m
+            .contents
+            .iter()
+            .chain(&m.assets)
+            .map(String::as_str)
+            .collect();
+        // a mod only counts once per tag
+        tags.sort_unstable();
+        tags.dedup();
+        for tag in tags {
+            *counts.entry(tag).or_insert(0) += 1;
+        }
+    }
+    let mut counts: Vec<(String, usize)> = counts
+        .into_iter()
+        .map(|(tag, n)| (tag.to_string(), n))
+        .collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example(contents: &[&str], assets: &[&str]) -> Mod {
+        Mod {
+            contents: contents.iter().map(|x| x.to_string()).collect(),
+            assets: assets.iter().map(|x| x.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn counted() {
+        let mods = vec![
+            example(&["blocks", "units"], &["content", "sprites"]),
+            example(&["blocks"], &["content"]),
+            example(&[], &["sprites", "sprites"]),
+        ];
+        let counts = tag_counts(&mods);
+        let expected: Vec<(String, usize)> = vec![
+            ("blocks".into(), 2),
+            ("content".into(), 2),
+            ("sprites".into(), 2),
+            ("units".into(), 1),
+        ];
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(tag_counts(&[]).is_empty());
+    }
+}
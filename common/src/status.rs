@@ -0,0 +1,68 @@
+//! Maintenance status of a mod, derived from the age of its last commit and
+//! whether its repository is archived, as a single glanceable indicator.
+
+use std::time::Duration;
+
+pub const ACTIVE: &str = "active";
+pub const STALE: &str = "stale";
+pub const ABANDONED: &str = "abandoned";
+
+/// Every status, in the order of the dropdown.
+pub const ALL: [&str; 3] = [ACTIVE, STALE, ABANDONED];
+
+const DAY: u64 = 24 * 60 * 60;
+
+/// Age of the last commit after which a mod is stale.
+pub const STALE_AFTER: Duration = Duration::from_secs(90 * DAY);
+
+/// Age of the last commit after which a mod is abandoned.
+pub const ABANDONED_AFTER: Duration = Duration::from_secs(365 * DAY);
+
+/// Infers the status from the `age` of the last commit, where archived
+/// repositories are abandoned whatever their age, and mods whose age is
+/// unknown are given the benefit of the doubt.
+pub fn infer(age: Option<Duration>, archived: bool) -> &'static str {
+    match age {
+        _ if archived => ABANDONED,
+        Some(age) if age > ABANDONED_AFTER => ABANDONED,
+        Some(age) if age > STALE_AFTER => STALE,
+        _ => ACTIVE,
+    }
+}
+
+/// Explanation of the thresholds, for tooltips.
+pub fn thresholds() -> String {
+    format!(
+        "{}: committed to within {} days; {}: within {} days; {}: older, or archived",
+        ACTIVE,
+        STALE_AFTER.as_secs() / DAY,
+        STALE,
+        ABANDONED_AFTER.as_secs() / DAY,
+        ABANDONED,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn days(n: u64) -> Option<Duration> {
+        Some(Duration::from_secs(n * DAY))
+    }
+
+    #[test]
+    fn by_age() {
+        assert_eq!(infer(days(0), false), ACTIVE);
+        assert_eq!(infer(days(90), false), ACTIVE);
+        assert_eq!(infer(days(91), false), STALE);
+        assert_eq!(infer(days(365), false), STALE);
+        assert_eq!(infer(days(366), false), ABANDONED);
+        assert_eq!(infer(None, false), ACTIVE);
+    }
+
+    #[test]
+    fn archived() {
+        assert_eq!(infer(days(0), true), ABANDONED);
+        assert_eq!(infer(None, true), ABANDONED);
+    }
+}
@@ -3,10 +3,231 @@
 
 mod path {
     use lazy_static::lazy_static;
-    pub const GITHUB_MARK: &str = "static/images/GitHub-Mark/PNG/GitHub-Mark-Light-64px.png";
-    pub const NOTHING: &str = "static/images/nothing.png";
+
+    /// Base path the app is hosted under, as in `"/mods/"`, set with the
+    /// `ROOT` environment variable at build time. When it's empty, paths
+    /// are relative to the page.
+    pub const ROOT: &str = match option_env!("ROOT") {
+        Some(root) => root,
+        None => "",
+    };
+
+    /// Path of a static file, relative to `ROOT`.
+    fn root(path: &str) -> String {
+        format!("{}{}", ROOT, path)
+    }
+
+    /// Name of the `<meta>` tag overriding the directory the data is fetched
+    /// from, as in `<meta name="data-source" content="https://example.com/data/">`,
+    /// for self-hosting against another backend. It isn't a query param,
+    /// since links could then show anyone's data under this site.
+    const DATA_SOURCE_META: &str = "data-source";
+
     lazy_static! {
-        pub static ref DATA: String = format!("static/data/modmeta.{}.json", common::MOD_VERSION);
+        pub static ref GITHUB_MARK: String =
+            root("static/images/GitHub-Mark/PNG/GitHub-Mark-Light-64px.png");
+        pub static ref NOTHING: String = root("static/images/nothing.png");
+    }
+
+    /// Directory the data is fetched from, ending with `/`: the content of
+    /// the `DATA_SOURCE_META` tag, or `static/data/` otherwise.
+    pub fn data_dir() -> String {
+        let meta = seed::document()
+            .query_selector(&format!("meta[name=\"{}\"]", DATA_SOURCE_META))
+            .ok()
+            .flatten()
+            .and_then(|el| el.get_attribute("content"))
+            .filter(|x| !x.trim().is_empty());
+        match meta {
+            Some(dir) if dir.ends_with('/') => dir,
+            Some(dir) => format!("{}/", dir),
+            None => root("static/data/"),
+        }
+    }
+
+    /// Mod data, in the data directory `dir`.
+    pub fn data(dir: &str) -> String {
+        format!("{}modmeta.{}.json", dir, common::MOD_VERSION)
+    }
+
+    /// First mods of `data`, which is small enough to list them while the
+    /// rest loads.
+    pub fn data_first(dir: &str) -> String {
+        format!("{}modmeta.{}.first.json", dir, common::MOD_VERSION)
+    }
+
+    /// Repositories which failed to be listed, in the data directory `dir`.
+    pub fn failures(dir: &str) -> String {
+        format!("{}failures.{}.json", dir, common::MOD_VERSION)
+    }
+}
+
+/// Markdown rendering, with linkable headings.
+mod markdown {
+    use super::app::Msg;
+    use common::sanitize;
+    use pulldown_cmark::{html, Event, LinkType, Parser, Tag};
+    use seed::prelude::*;
+    use std::collections::HashMap;
+
+    /// Slug of a heading, the way GitHub makes its anchors: lowercased,
+    /// without punctuation, and with spaces as `-`.
+    pub fn slugify(text: &str) -> String {
+        text.trim()
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphanumeric() || *c == ' ' || *c == '-' || *c == '_')
+            .map(|c| if c == ' ' { '-' } else { c })
+            .collect()
+    }
+
+    /// Events of markdown, with its raw html sanitized, and the unsafe urls of
+    /// links and images removed. Consecutive html is sanitized as a whole,
+    /// since html blocks come a line at a time. The other urls are passed
+    /// through `urls`, along with whether they're of an image.
+    fn sanitized<'a>(markdown: &'a str, urls: &dyn Fn(&str, bool) -> String) -> Vec<Event<'a>> {
+        let html_urls = |tag: &str, url: &str| urls(url, tag == "img");
+        let mut events = Vec::new();
+        let mut raw = String::new();
+        for event in Parser::new(markdown) {
+            let event = match event {
+                Event::Html(x) => {
+                    raw.push_str(&x);
+                    continue;
+                }
+                // as in `<someone@example.com>`, which gets `mailto:` when rendered
+                Event::Start(Tag::Link(LinkType::Email, url, title)) => {
+                    Event::Start(Tag::Link(LinkType::Email, url, title))
+                }
+                Event::Start(Tag::Link(kind, url, title)) => {
+                    let url = if sanitize::is_safe_url(&url) {
+                        urls(&url, false)
+                    } else {
+                        String::new()
+                    };
+                    Event::Start(Tag::Link(kind, url.into(), title))
+                }
+                Event::Start(Tag::Image(kind, url, title)) => {
+                    let url = if sanitize::is_safe_url(&url) {
+                        urls(&url, true)
+                    } else {
+                        String::new()
+                    };
+                    Event::Start(Tag::Image(kind, url.into(), title))
+                }
+                event => event,
+            };
+            if !raw.is_empty() {
+                events.push(Event::Html(
+                    sanitize::html_with_urls(&raw, &html_urls).into(),
+                ));
+                raw.clear();
+            }
+            events.push(event);
+        }
+        if !raw.is_empty() {
+            events.push(Event::Html(
+                sanitize::html_with_urls(&raw, &html_urls).into(),
+            ));
+        }
+        events
+    }
+
+    /// Converts markdown to html nodes, where headings have slugified ids,
+    /// and a `#` link to themselves. Repeated slugs get a `-1`, `-2`... suffix.
+    /// Raw html in it is sanitized, since anyone can write a README, and its
+    /// urls are passed through `urls`, along with whether they're of an image.
+    pub fn to_nodes(markdown: &str, urls: &dyn Fn(&str, bool) -> String) -> Vec<Node<Msg>> {
+        let events = sanitized(markdown, urls);
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        let mut output = Vec::with_capacity(events.len());
+        let mut i = 0;
+        while i < events.len() {
+            if let Event::Start(Tag::Heading(level)) = &events[i] {
+                let end = events[i..]
+                    .iter()
+                    .position(|e| match e {
+                        Event::End(Tag::Heading(_)) => true,
+                        _ => false,
+                    })
+                    .map_or(events.len(), |x| i + x);
+                let inner = &events[i + 1..end];
+                let text: String = inner
+                    .iter()
+                    .filter_map(|e| match e {
+                        Event::Text(x) | Event::Code(x) => Some(x.as_ref()),
+                        _ => None,
+                    })
+                    .collect();
+                let mut slug = slugify(&text);
+                let count = seen.entry(slug.clone()).or_insert(0);
+                if *count > 0 {
+                    slug = format!("{}-{}", slug, count);
+                }
+                *count += 1;
+                output.push(Event::Html(
+                    format!(
+                        r##"<h{0} id="{1}"><a class="anchor" href="#{1}">#</a>"##,
+                        level, slug
+                    )
+                    .into(),
+                ));
+                output.extend(inner.iter().cloned());
+                output.push(Event::Html(format!("</h{}>", level).into()));
+                i = end + 1;
+            } else {
+                output.push(events[i].clone());
+                i += 1;
+            }
+        }
+        let mut html_text = String::new();
+        html::push_html(&mut html_text, output.into_iter());
+        Node::from_html(&html_text)
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        /// Html of markdown, with the urls resolved as for a README.
+        fn render(markdown: &str) -> String {
+            let urls = |url: &str, _| format!("https://github.com/user/repo/blob/main/{}", url);
+            let mut output = String::new();
+            html::push_html(&mut output, sanitized(markdown, &urls).into_iter());
+            output
+        }
+
+        #[test]
+        fn email_links() {
+            assert_eq!(
+                render("<someone@example.com>"),
+                "<p><a href=\"mailto:someone@example.com\">someone@example.com</a></p>\n"
+            );
+            assert_eq!(
+                render("[guide](docs/guide.md)"),
+                "<p><a href=\"https://github.com/user/repo/blob/main/docs/guide.md\">guide</a></p>\n"
+            );
+        }
+    }
+}
+
+/// Browser local storage of user preferences.
+mod storage {
+    use serde::{de::DeserializeOwned, Serialize};
+
+    /// Loads a value, or `None` if it's missing or can't be decoded.
+    pub fn load<T: DeserializeOwned>(key: &str) -> Option<T> {
+        let json = seed::storage::get_storage()?.get_item(key).ok()??;
+        serde_json::from_str(&json).ok()
+    }
+
+    /// Stores a value, ignoring failures such as storage being full or disabled.
+    pub fn store<T: Serialize>(key: &str, value: &T) {
+        if let (Some(storage), Ok(json)) =
+            (seed::storage::get_storage(), serde_json::to_string(value))
+        {
+            let _ = storage.set_item(key, &json);
+        }
     }
 }
 
@@ -40,21 +261,158 @@ mod date {
         from_tt(x)
     }
 
+    /// Current time, as a UTC timestamp epoch in seconds.
+    pub fn now_secs() -> f64 {
+        Date::now() / 1_000.0
+    }
+
     /// Parses weak rfc3339 time stamps and returns the duration since now.
     pub fn ago(date: &str) -> Result<Duration, Error> {
         let sys = parse_rfc3339_weak(date)?;
         let ago = now().duration_since(sys)?;
         Ok(ago)
     }
+
+    /// Language of relative times, with English as the fallback.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Lang {
+        En,
+        Fr,
+        De,
+        Es,
+        Pt,
+    }
+
+    impl Lang {
+        /// Language of a BCP 47 tag, as in `"fr-CA"`.
+        pub fn from_tag(tag: &str) -> Self {
+            match tag.split('-').next().unwrap_or("").to_lowercase().as_str() {
+                "fr" => Self::Fr,
+                "de" => Self::De,
+                "es" => Self::Es,
+                "pt" => Self::Pt,
+                _ => Self::En,
+            }
+        }
+
+        /// Language of the browser, from `navigator.language`.
+        pub fn detect() -> Self {
+            let tag = web_sys::window().and_then(|x| x.navigator().language());
+            Self::from_tag(tag.as_deref().unwrap_or(""))
+        }
+    }
+
+    lazy_static::lazy_static! {
+        /// Language of the browser, detected once.
+        pub static ref LANG: Lang = Lang::detect();
+    }
+
+    /// Units of relative times, from largest to smallest, in seconds.
+    const UNITS: [(Unit, u64); 6] = [
+        (Unit::Year, 31_557_600),
+        (Unit::Month, 2_629_800),
+        (Unit::Day, 86_400),
+        (Unit::Hour, 3_600),
+        (Unit::Minute, 60),
+        (Unit::Second, 1),
+    ];
+
+    #[derive(Clone, Copy)]
+    enum Unit {
+        Year,
+        Month,
+        Day,
+        Hour,
+        Minute,
+        Second,
+    }
+
+    /// Singular and plural name of a unit.
+    fn unit_name(unit: Unit, lang: Lang) -> (&'static str, &'static str) {
+        use {Lang::*, Unit::*};
+        match (lang, unit) {
+            (En, Year) => ("year", "years"),
+            (En, Month) => ("month", "months"),
+            (En, Day) => ("day", "days"),
+            (En, Hour) => ("hour", "hours"),
+            (En, Minute) => ("minute", "minutes"),
+            (En, Second) => ("second", "seconds"),
+            (Fr, Year) => ("an", "ans"),
+            (Fr, Month) => ("mois", "mois"),
+            (Fr, Day) => ("jour", "jours"),
+            (Fr, Hour) => ("heure", "heures"),
+            (Fr, Minute) => ("minute", "minutes"),
+            (Fr, Second) => ("seconde", "secondes"),
+            (De, Year) => ("Jahr", "Jahren"),
+            (De, Month) => ("Monat", "Monaten"),
+            (De, Day) => ("Tag", "Tagen"),
+            (De, Hour) => ("Stunde", "Stunden"),
+            (De, Minute) => ("Minute", "Minuten"),
+            (De, Second) => ("Sekunde", "Sekunden"),
+            (Es, Year) => ("año", "años"),
+            (Es, Month) => ("mes", "meses"),
+            (Es, Day) => ("día", "días"),
+            (Es, Hour) => ("hora", "horas"),
+            (Es, Minute) => ("minuto", "minutos"),
+            (Es, Second) => ("segundo", "segundos"),
+            (Pt, Year) => ("ano", "anos"),
+            (Pt, Month) => ("mês", "meses"),
+            (Pt, Day) => ("dia", "dias"),
+            (Pt, Hour) => ("hora", "horas"),
+            (Pt, Minute) => ("minuto", "minutos"),
+            (Pt, Second) => ("segundo", "segundos"),
+        }
+    }
+
+    /// Formats a duration as a relative time in the past, in its largest
+    /// unit, as in `"3 months ago"` or `"il y a 3 mois"`.
+    pub fn format_ago(ago: Duration, lang: Lang) -> String {
+        let secs = ago.as_secs();
+        let (unit, n) = UNITS
+            .iter()
+            .map(|&(unit, size)| (unit, secs / size))
+            .find(|&(_, n)| n > 0)
+            .unwrap_or((Unit::Second, 0));
+        let (singular, plural) = unit_name(unit, lang);
+        let name = if n == 1 { singular } else { plural };
+        match lang {
+            Lang::En => format!("{} {} ago", n, name),
+            Lang::Fr => format!("il y a {} {}", n, name),
+            Lang::De => format!("vor {} {}", n, name),
+            Lang::Es => format!("hace {} {}", n, name),
+            Lang::Pt => format!("há {} {}", n, name),
+        }
+    }
 }
 
 /// Mod listing functions.
 mod listing {
-    use super::{app::Msg, app::Page, date, markup, path};
-    use common::Mod;
+    use super::{app::Msg, app::OverviewTab, app::Page, date, markdown, markup, path};
+    use common::{links, search, status, Mod, TRUSTED_ORGS};
     use seed::{prelude::*, *};
     use serde::Deserialize;
-    use std::{convert::TryFrom, iter};
+    use std::{convert::TryFrom, iter, time::Duration};
+
+    /// Glyph for a known content/asset category, as named by the
+    /// `Contents`/`Assets` variants of the backend.
+    fn tiny_icon(name: &str) -> Option<&'static str> {
+        Some(match name {
+            "items" => "📦",
+            "blocks" => "🧱",
+            "mechs" => "🤖",
+            "liquids" => "💧",
+            "units" => "🚀",
+            "zones" => "🗺",
+            "bundles" => "🌐",
+            "sounds" => "🔊",
+            "schematics" => "📐",
+            "sprites-override" => "🖌",
+            "sprites" => "🎨",
+            "scripts" => "📜",
+            "maps" => "🗺",
+            _ => return None,
+        })
+    }
 
     /// Makes the tiny contents/assets overview lists.
     fn tiny_list(v: &[String]) -> Node<Msg> {
@@ -64,7 +422,14 @@ mod listing {
                 "content" => None,
                 string => Some(string),
             })
-            .map(|x| li![attrs! { At::Class => x}, x]);
+            .map(|x| match tiny_icon(x) {
+                Some(icon) => li![
+                    attrs! { At::Class => x},
+                    span![attrs! { At::Class => "tiny-icon" }, icon],
+                    x
+                ],
+                None => li![attrs! { At::Class => x}, x],
+            });
 
         if it.clone().take(1).next().is_some() {
             ul![it]
@@ -73,28 +438,206 @@ mod listing {
         }
     }
 
+    /// Star counts above which a single enlarged star glyph is rendered,
+    /// instead of one glyph per star, which keeps popular mods from creating
+    /// thousands of DOM nodes.
+    const MAX_STAR_GLYPHS: usize = 10;
+
+    /// Formats a number with `,` thousands separators, as in `"1,234"`.
+    pub fn thousands(n: u32) -> String {
+        let digits = n.to_string();
+        let mut output = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                output.push(',');
+            }
+            output.push(c);
+        }
+        output
+    }
+
+    /// Formats a size in kilobytes, as in `"340 KB"` or `"1.2 MB"`.
+    fn file_size(kb: u32) -> String {
+        if kb < 1024 {
+            format!("{} KB", kb)
+        } else {
+            format!("{:.1} MB", f64::from(kb) / 1024.0)
+        }
+    }
+
+    /// Button copying `text` to the clipboard, with a confirmation which fades
+    /// out when it's the `copied` text; `copies` counts the copies on the
+    /// page.
+    fn copy_button(text: String, label: &str, copies: u32, copied: &str) -> Vec<Node<Msg>> {
+        let confirmation = "copied!";
+        let is_copied = copies > 0 && text == copied;
+        vec![
+            button![
+                attrs! { "aria-label" => label },
+                simple_ev(Ev::Click, Msg::CopyText(text)),
+                "copy"
+            ],
+            // alternates tags, such that the element is replaced and its fade
+            // out animation restarts on every copy
+            match copies {
+                _ if !is_copied => empty![],
+                n if n % 2 == 0 => span![class!["copied"], confirmation],
+                _ => em![class!["copied"], confirmation],
+            },
+        ]
+    }
+
+    /// Accent color of mods without an icon, or whose icon couldn't be read.
+    const NEUTRAL_ACCENT: &str = "#444";
+
+    /// Prefix of search terms matching mods by dependency, as in
+    /// `depends:some-lib`.
+    pub const DEPENDS: &str = "depends:";
+
+    /// Prefix of search terms matching mods by one of their authors, as in
+    /// `author:some-one`, with spaces replaced by dashes.
+    pub const AUTHOR: &str = "author:";
+
+    /// Prefix of the element ids of listed mods, which keeps them apart from
+    /// the README heading ids of the overview.
+    pub const ANCHOR_PREFIX: &str = "mod-";
+
+    /// Lines listing descriptions are clamped to, matching `.clamped` in css.
+    const DESCRIPTION_LINES: usize = 3;
+
+    /// Image `onerror` handler, trying the next of its `data-fallbacks`, and
+    /// removing itself once there's none left.
+    const ICON_ONERROR: &str = "var c = this.dataset.fallbacks.split(' '); \
+        this.dataset.fallbacks = c.slice(1).join(' '); \
+        if (c[0]) { this.src = c[0]; } else { this.onerror = null; }";
+
+    /// Search term of an author, for `AUTHOR` searches.
+    pub fn author_term(name: &str) -> String {
+        name.trim().replace(' ', "-")
+    }
+
+    /// Side by side comparison of mods, with a column per mod and a row per
+    /// property.
+    pub fn compare(items: &[&ListingItem]) -> Node<Msg> {
+        let row = |label: &str, cell: &dyn Fn(&ListingItem) -> Node<Msg>| {
+            tr![th![label], items.iter().map(|x| td![cell(x)])]
+        };
+        table![
+            attrs! { At::Class => "compare" },
+            tr![
+                th![],
+                items
+                    .iter()
+                    .map(|x| th![div![class!["box icon"], x.icon()], x.title_link()])
+            ],
+            row("stars", &|x| span![thousands(x.0.stars)]),
+            row("last commit", &|x| x.last_commit()),
+            row("status", &|x| span![x.status()]),
+            row("version", &|x| {
+                span![markup::from_str(x.0.version.as_deref().unwrap_or("none"))]
+            }),
+            row("game version", &|x| {
+                span![x.0.min_game_version.as_deref().unwrap_or("any")]
+            }),
+            row("category", &|x| span![&x.0.category]),
+            row("contents", &|x| x.contents_list()),
+            row("assets", &|x| x.assets_list()),
+            row("languages", &|x| x.locales_list()),
+            row("dependencies", &|x| match x.0.dependencies.as_slice() {
+                [] => span!["none"],
+                deps => span![deps.join(", ")],
+            }),
+            row("license", &|x| x.license()),
+        ]
+    }
+
     /// Wraps mod meta data.
     #[derive(Deserialize, Debug, Clone)]
     pub struct ListingItem(pub Mod);
 
     impl ListingItem {
         /// Returns whether the mod should be rendered, given a query.
+        ///
+        /// The author searched is the plain one, without color markup, which
+        /// is left to `author_markup` for display. Terms separated by spaces
+        /// must all match, and those separated by `|` only one of them, see
+        /// `search::parse`.
         pub fn filtering(&self, query: &str) -> bool {
-            if query == "" {
-                true
-            } else {
-                query.split_whitespace().all(|q| {
-                    [
-                        &self.0.author,
-                        &self.0.desc,
-                        &self.0.repo,
-                        &self.0.readme,
-                        &self.0.contents.join(" "),
-                        &self.0.assets.join(" "),
-                    ]
-                    .iter()
-                    .any(|s| s.as_str().to_lowercase().contains(q))
+            search::matches(&query.to_lowercase(), |q| {
+                if let Some(name) = q.strip_prefix(DEPENDS) {
+                    self.0.depends_on(name)
+                } else if let Some(author) = q.strip_prefix(AUTHOR) {
+                    self.0.is_by(author)
+                } else {
+                    self.matches_outside_readme(q) || self.0.readme.to_lowercase().contains(q)
+                }
+            })
+        }
+
+        /// Whether a lowercase search term matches anything but the README.
+        fn matches_outside_readme(&self, term: &str) -> bool {
+            [
+                &self.0.author,
+                &self.0.desc,
+                &self.0.repo,
+                &self.0.contents.join(" "),
+                &self.0.assets.join(" "),
+                &self.0.locales.join(" "),
+            ]
+            .iter()
+            .any(|s| s.as_str().to_lowercase().contains(term))
+        }
+
+        /// The README line of the first search term which only matches the
+        /// README, split around the match, with some context on each side.
+        fn readme_snippet(&self, query: &str) -> Option<(String, String, String)> {
+            const CONTEXT: usize = 40;
+            let query = query.to_lowercase();
+            let (term, line) = search::parse(&query)
+                .into_iter()
+                .flatten()
+                .filter(|q| {
+                    !q.starts_with(DEPENDS)
+                        && !q.starts_with(AUTHOR)
+                        && !self.matches_outside_readme(q)
                 })
+                .find_map(|term| {
+                    let line = self
+                        .0
+                        .readme
+                        .lines()
+                        .find(|line| line.to_lowercase().contains(term))?;
+                    Some((term, line.trim()))
+                })?;
+            let lower = line.to_lowercase();
+            let start = lower.find(term)?;
+            // lowercasing may change lengths outside of ascii, in which case
+            // the indices don't line up and the line is shown as is
+            let matched = match line.get(start..start + term.len()) {
+                Some(matched) if lower.len() == line.len() => matched,
+                _ => return Some((String::new(), String::new(), line.to_string())),
+            };
+            let before: Vec<char> = line[..start].chars().collect();
+            let before: String = before[before.len().saturating_sub(CONTEXT)..]
+                .iter()
+                .collect();
+            let after: String = line[start + term.len()..].chars().take(CONTEXT).collect();
+            Some((before, matched.to_string(), after))
+        }
+
+        /// Snippet of the README around a search term which only matches it.
+        fn snippet(&self, query: &str) -> Node<Msg> {
+            match self.readme_snippet(query) {
+                Some((before, matched, after)) => div![
+                    attrs! { At::Class => "snippet" },
+                    span![style! { St::Opacity => "60%" }, "README: "],
+                    "…",
+                    before,
+                    mark![matched],
+                    after,
+                    "…"
+                ],
+                None => empty![],
             }
         }
 
@@ -113,20 +656,40 @@ mod listing {
                     At::Href => self.0.archive_link(),
                     At::Target => "_self"
                 },
-                "zip"
+                match self.0.size_kb {
+                    Some(kb) => format!("zip, ~{}", file_size(kb)),
+                    None => "zip".into(),
+                }
             ]
         }
 
-        /// Endpoint link as a string.
+        /// Endpoint link to the pre-rendered page as a string.
         fn _endpoint_href(&self) -> String {
-            let path = self.0.repo.replace("/", "--");
-            format!("m/{}.html", path).into()
+            format!("m/{}.html", self.endpoint_query())
         }
 
         /// Endpoint url query string for mod. Essentially used as an ID internally.
         pub fn endpoint_query(&self) -> String {
-            let path = self.0.repo.replace("/", "--");
-            format!("{}", path)
+            self.0.endpoint_query()
+        }
+
+        /// Element id of the mod in the listing, which is linked to by the url
+        /// fragment, like `#mod-Anuken--ExampleMod`.
+        pub fn anchor(&self) -> String {
+            format!("{}{}", ANCHOR_PREFIX, self.endpoint_query())
+        }
+
+        /// Link to the position of the mod in the listing.
+        fn permalink(&self) -> Node<Msg> {
+            a![
+                attrs! {
+                    At::Class => "permalink",
+                    At::Href => format!("#{}", self.anchor()),
+                    At::Title => "Link to this mod in the listing",
+                    "aria-label" => "Link to this mod in the listing",
+                },
+                "#"
+            ]
         }
 
         // /// Endpoint link to the locally rendered README.md
@@ -136,14 +699,32 @@ mod listing {
 
         /// Link to the mods repository.
         fn repo_link(&self) -> Node<Msg> {
-            a![attrs! { At::Href => self.0.link }, "repository"]
+            a![
+                attrs! {
+                    At::Href => self.0.link,
+                    At::Target => "_blank",
+                    At::Rel => "noopener",
+                },
+                "repository"
+            ]
+        }
+
+        /// Badge of the license, by its SPDX identifier when it has one.
+        fn license(&self) -> Node<Msg> {
+            span![
+                attrs! { At::Class => "license", At::Title => "License" },
+                self.0.license.as_deref().unwrap_or("no license")
+            ]
         }
 
         /// Optional link to a wiki.
         fn wiki_link(&self) -> Node<Msg> {
             // TODO: remove this?
             // match &self.0.wiki {
-            //     Some(link) => a![attrs! { At::Href => link }, "wiki"],
+            //     Some(link) => a![
+            //         attrs! { At::Href => link, At::Target => "_blank", At::Rel => "noopener" },
+            //         "wiki"
+            //     ],
             //     None => a![style! { "display" => "none" }],
             // }
             a![style! { "display" => "none" }]
@@ -157,29 +738,60 @@ mod listing {
             }
         }
 
+        /// Time since the last commit, from its date, or from its timestamp
+        /// when the date isn't valid rfc3339, as some repositories have
+        /// inconsistent dates. Errors only when both are unusable.
+        fn commit_age(&self) -> Result<Duration, date::Error> {
+            date::ago(&self.0.date).or_else(|e| self.0.age(date::now_secs()).ok_or(e))
+        }
+
+        /// Time since the repository was created, from its date like
+        /// `commit_age`, or `None` when it's unknown.
+        fn created_age(&self) -> Option<Duration> {
+            if self.0.created.is_empty() {
+                return None;
+            }
+            date::ago(&self.0.created)
+                .ok()
+                .or_else(|| self.0.created_age(date::now_secs()))
+        }
+
+        /// When the mod was last updated and created, for the overview.
+        fn dates(&self) -> Node<Msg> {
+            let ago = |age: Duration| date::format_ago(age, *date::LANG);
+            let updated = self
+                .commit_age()
+                .ok()
+                .map(|x| format!("updated: {}", ago(x)));
+            let created = self.created_age().map(|x| format!("created: {}", ago(x)));
+            let dates: Vec<String> = updated.into_iter().chain(created).collect();
+            if dates.is_empty() {
+                empty![]
+            } else {
+                p![class!["dates"], dates.join(" · ")]
+            }
+        }
+
         /// The rendered `time age` string.
         fn last_commit(&self) -> Node<Msg> {
             // NOTE: may want to consider using chrono instead.
-            use itertools::Itertools;
-            let fmt_ago = match date::ago(&self.0.date) {
-                Ok(d) => format!("{}", humantime::format_duration(d))
-                    .split(" ")
-                    .interleave(iter::repeat(" ago"))
-                    .take(2)
-                    .collect(),
-
-                Err(date::Error::Computation(_)) => "computation error".to_string(),
-                Err(date::Error::Formatting(_)) => "formatting error".to_string(),
-            };
-
-            div![attrs! { At::Class => "last-commit" }, fmt_ago]
+            match self.commit_age() {
+                Ok(d) => div![
+                    attrs! { At::Class => "last-commit" },
+                    date::format_ago(d, *date::LANG)
+                ],
+                Err(e) => div![
+                    attrs! { At::Class => "last-commit", At::Title => e.to_string() },
+                    "unknown date"
+                ],
+            }
         }
 
         /// Returns unicode stars.
         fn stars_el(&self) -> Node<Msg> {
             let star_count: Node<Msg> = div![
                 attrs! { At::Class => "star-count" },
-                format!("{}", self.0.stars)
+                thousands(self.0.stars)
             ];
             match usize::try_from(self.0.stars) {
                 Err(_) => div![star_count, div!["err"]],
@@ -187,6 +799,16 @@ mod listing {
                     div![attrs! { At::Class => "stars-wrapper"}, "☆"],
                     star_count,
                 ],
+                Ok(n) if n > MAX_STAR_GLYPHS => div![
+                    div![
+                        attrs! {
+                            At::Class => "stars-wrapper popular",
+                            At::Title => format!("{} stars", thousands(self.0.stars)),
+                        },
+                        div![attrs! { At::Class => "star" }, "★"]
+                    ],
+                    star_count,
+                ],
                 Ok(n) => div![
                     div![
                         attrs! { At::Class => "stars-wrapper" },
@@ -201,45 +823,274 @@ mod listing {
 
         /// Returns an icon link node.
         ///
-        /// 1. uses `icon.png` from `default_branch`
-        /// 2  falls back to github user icon
+        /// 1. uses the icon path (or `icon.png`) from `default_branch`
+        /// 2. falls back to `main` then `master`, for icons on other refs
+        /// 3. falls back to github user icon
+        /// 4. falls back to the `nothing.png` placeholder
+        ///
+        /// Fallbacks are cycled through by the image `onerror` handler, which
+        /// pops the next candidate off the space separated `data-fallbacks`.
         fn icon(&self) -> Node<Msg> {
-            let icon = format!(
-                "https://raw.githubusercontent.com/{}/{}/icon.png",
-                self.0.repo, self.0.default_branch,
-            );
-            let user_icon = match self.0.repo.split("/").next() {
-                Some(user) => format!("https://github.com/{}.png?size=64", user),
-                None => path::NOTHING.into(),
-            };
+            let candidates = self.icon_urls();
             button![
+                attrs! { "aria-label" => self.overview_label() },
                 simple_ev(Ev::Click, Msg::Route(Page::Overview(self.endpoint_query()))),
                 img![attrs! {
-                    At::Src => &icon,
-                    At::OnError => format!("this.src='{}'", user_icon),
+                    At::Src => &candidates[0],
+                    At::Alt => "",
+                    "data-fallbacks" => candidates[1..].join(" "),
+                    At::OnError => ICON_ONERROR,
                 }]
             ]
         }
 
-        /// Description paragraph of the mode for the listing.
-        fn description(&self) -> Node<Msg> {
-            p![
-                style! { St::Background => "#0f0f0f" },
-                attrs! { At::Class => "description" },
-                match self.0.desc_markup.as_ref() {
-                    Some(x) => markup::from_str(x),
-                    None => vec![],
-                }
+        /// Accessible label of the buttons opening the overview.
+        fn overview_label(&self) -> String {
+            format!("Open {} overview", self.0.name)
+        }
+
+        /// Icon urls, in the order they should be tried. Never empty.
+        ///
+        /// An icon override which is already an absolute url, hosted outside
+        /// of the repository, is tried as it is.
+        pub fn icon_urls(&self) -> Vec<String> {
+            let icon = self.0.icon.as_deref().unwrap_or("icon.png").trim();
+            let mut candidates: Vec<String> =
+                if icon.starts_with("http://") || icon.starts_with("https://") {
+                    vec![icon.replace(" ", "%20")]
+                } else {
+                    let path = icon.trim_start_matches('/').replace(" ", "%20");
+                    let mut branches = vec![self.0.default_branch.as_str()];
+                    for branch in &["main", "master"] {
+                        if !branches.contains(branch) {
+                            branches.push(branch);
+                        }
+                    }
+                    branches
+                        .iter()
+                        .map(|branch| {
+                            format!(
+                                "https://raw.githubusercontent.com/{}/{}/{}",
+                                self.0.repo, branch, path
+                            )
+                        })
+                        .collect()
+                };
+            if let Some(user) = self.0.repo.split("/").next() {
+                candidates.push(format!("https://github.com/{}.png?size=64", user));
+            }
+            candidates.push(path::NOTHING.to_string());
+            candidates
+        }
+
+        /// Whether the description may not fit in the lines it's clamped to,
+        /// since whether it overflows is only known once laid out.
+        fn long_description(&self) -> bool {
+            let desc = self.0.desc_markup.as_deref().unwrap_or("");
+            desc.lines().count() > DESCRIPTION_LINES || desc.len() > 40 * DESCRIPTION_LINES
+        }
+
+        /// Accent color of the listing card, from the icon.
+        fn accent(&self) -> &str {
+            self.0.accent.as_deref().unwrap_or(NEUTRAL_ACCENT)
+        }
+
+        /// Description paragraph of the mode for the listing, clamped to
+        /// `DESCRIPTION_LINES` unless `expanded`.
+        fn description(&self, expanded: bool) -> Vec<Node<Msg>> {
+            let long = self.long_description();
+            vec![
+                p![
+                    style! { St::Background => "#0f0f0f" },
+                    class!["description", "clamped" => long && !expanded],
+                    match self.0.desc_markup.as_ref() {
+                        Some(x) => markup::from_str(x),
+                        None => vec![],
+                    }
+                ],
+                if long {
+                    button![
+                        attrs! {
+                            At::Class => "show-more",
+                            "aria-expanded" => expanded.to_string(),
+                        },
+                        simple_ev(Ev::Click, Msg::ToggleDescription(self.endpoint_query())),
+                        if expanded { "show less" } else { "show more" }
+                    ]
+                } else {
+                    empty![]
+                },
             ]
         }
 
-        /// The rendered author.
+        /// The rendered authors, each searching the listing for their mods
+        /// when clicked. A single author keeps its color markup.
         fn by_author(&self) -> Node<Msg> {
+            let link = |name: &str, content: Vec<Node<Msg>>| {
+                button![
+                    attrs! {
+                        At::Class => "author-link",
+                        At::Title => format!("Search {}{}", AUTHOR, author_term(name)),
+                    },
+                    simple_ev(Ev::Click, Msg::ListByAuthor(name.to_string())),
+                    content
+                ]
+            };
+            let markup = self.0.author_markup.as_deref().unwrap_or("null");
+            let links: Vec<Node<Msg>> = match self.0.author_names().as_slice() {
+                [""] => markup::from_str(markup),
+                [name] => vec![link(name, markup::from_str(markup))],
+                names => names
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, name)| {
+                        let separator = if i == 0 {
+                            empty![]
+                        } else {
+                            Node::new_text(", ")
+                        };
+                        vec![
+                            separator,
+                            link(name, vec![Node::new_text(name.to_string())]),
+                        ]
+                    })
+                    .collect(),
+            };
             div![
                 attrs! { At::Class => "by-author" },
                 style! { St::Opacity => "60%" },
                 "by ",
-                markup::from_str(self.0.author_markup.as_deref().unwrap_or("null"))
+                links
+            ]
+        }
+
+        /// Whether the last commit is at most `max_age` old. Mods with dates
+        /// which can't be parsed are kept, since there's nothing to tell.
+        pub fn updated_within(&self, max_age: Duration) -> bool {
+            self.commit_age().map_or(true, |ago| ago <= max_age)
+        }
+
+        /// Whether the mod is owned by one of the `TRUSTED_ORGS`.
+        pub fn is_official(&self) -> bool {
+            self.0.is_trusted(TRUSTED_ORGS)
+        }
+
+        /// The rendered badge of official mods.
+        fn verified_badge(&self) -> Node<Msg> {
+            if self.is_official() {
+                span![
+                    attrs! { At::Class => "verified", At::Title => "Official mod" },
+                    "✔ official"
+                ]
+            } else {
+                empty![]
+            }
+        }
+
+        /// Badge of mods hand-picked by the maintainers.
+        fn featured_badge(&self) -> Node<Msg> {
+            if self.0.featured {
+                span![
+                    attrs! { At::Class => "featured-badge", At::Title => "Picked by the maintainers" },
+                    "★ featured"
+                ]
+            } else {
+                empty![]
+            }
+        }
+
+        /// Small tile of the featured section, which leaves the element id to
+        /// the card of the mod in the listing below.
+        pub fn featured_tile(&self) -> Node<Msg> {
+            div![
+                attrs! { At::Class => "featured-tile", "role" => "listitem" },
+                div![attrs! { At::Class => "box icon" }, self.icon()],
+                self.title_link(),
+                self.by_author(),
+            ]
+        }
+
+        /// Badge of JavaScript mods, which have a `mod.json` main script.
+        fn js_badge(&self) -> Node<Msg> {
+            if self.0.is_js_mod {
+                span![
+                    attrs! { At::Class => "js-badge", At::Title => "Scripting mod" },
+                    "JS"
+                ]
+            } else {
+                empty![]
+            }
+        }
+
+        /// Badge of translation mods, which only ship bundles, titled with
+        /// their languages.
+        fn translation_badge(&self) -> Node<Msg> {
+            if !self.0.is_translation {
+                return empty![];
+            }
+            let title = match self.0.locales.as_slice() {
+                [] => "Translation mod".to_string(),
+                locales => format!("Translation mod: {}", locales.join(", ")),
+            };
+            span![
+                attrs! { At::Class => "translation-badge", At::Title => title },
+                "🌐 translation"
+            ]
+        }
+
+        /// Languages of the bundles, as locale codes.
+        fn locales_list(&self) -> Node<Msg> {
+            match self.0.locales.as_slice() {
+                [] => span!["none"],
+                locales => span![locales.join(", ")],
+            }
+        }
+
+        /// Badge of mods whose repository is archived, which only shows up
+        /// when the data was generated including them.
+        fn archived_badge(&self) -> Node<Msg> {
+            if self.0.archived {
+                span![
+                    attrs! { At::Class => "archived", At::Title => "The repository is archived" },
+                    "archived"
+                ]
+            } else {
+                empty![]
+            }
+        }
+
+        /// Maintenance status, computed now for data from before `status`.
+        pub fn status(&self) -> &str {
+            if self.0.status.is_empty() {
+                self.0.status_at(date::now_secs())
+            } else {
+                &self.0.status
+            }
+        }
+
+        /// Dot colored by the maintenance status, which filters the listing
+        /// by it.
+        fn status_dot(&self) -> Node<Msg> {
+            let status = self.status();
+            button![
+                attrs! {
+                    At::Class => format!("status-dot {}", status),
+                    At::Title => format!("{} ({})", status, status::thresholds()),
+                    "aria-label" => status,
+                },
+                simple_ev(Ev::Click, Msg::SetStatus(status.to_string())),
+            ]
+        }
+
+        /// Chip of the category, which filters the listing by it.
+        fn category_chip(&self) -> Node<Msg> {
+            if self.0.category.is_empty() {
+                return empty![];
+            }
+            button![
+                attrs! { At::Class => "category-chip", At::Title => "Only list this category" },
+                simple_ev(Ev::Click, Msg::SetCategory(self.0.category.clone())),
+                self.0.category
             ]
         }
 
@@ -265,51 +1116,163 @@ mod listing {
                 attrs! { At::Class => "title-link" },
                 button![
                     style! { St::Background => "#282828" },
+                    attrs! { "aria-label" => self.overview_label() },
                     simple_ev(Ev::Click, Msg::Route(Page::Overview(self.endpoint_query()))),
                     markup::from_str(name),
                 ]
             ]
         }
 
-        /// Title (name) of the mod in the listing.
-        fn listing_title(&self) -> Node<Msg> {
+        /// Badge of mods committed to recently, when `fresh`.
+        fn new_badge(&self, fresh: bool) -> Node<Msg> {
+            if fresh {
+                span![
+                    attrs! { At::Class => "new-badge", At::Title => "Updated recently" },
+                    "new"
+                ]
+            } else {
+                empty![]
+            }
+        }
+
+        /// Title (name) of the mod in the listing, with the new badge when
+        /// `fresh`.
+        fn listing_title(&self, fresh: bool) -> Node<Msg> {
             div![
                 attrs! { At::Class => "title-box" },
+                self.status_dot(),
                 self.title_link(),
+                self.verified_badge(),
+                self.featured_badge(),
+                self.new_badge(fresh),
+                self.js_badge(),
+                self.translation_badge(),
+                self.archived_badge(),
+                self.category_chip(),
                 self.by_author(),
                 self.v_number(),
                 self.last_commit()
             ]
         }
 
-        /// Returns the `Node<Msg>` for the listing.
-        pub fn listing_item(&self) -> Node<Msg> {
+        /// Returns the single line `Node<Msg>` for the compact listing.
+        pub fn compact_item(&self) -> Node<Msg> {
             div![
-                attrs! { At::Class => "outside" },
+                attrs! { At::Class => "outside", At::Id => self.anchor(), "role" => "listitem" },
                 div![
-                    attrs! { At::Class => "wrapper" },
+                    attrs! { At::Class => "compact" },
                     div![attrs! { At::Class => "box icon" }, self.icon()],
-                    div![attrs! { At::Class => "box name" }, self.listing_title()],
-                    div![attrs! { At::Class => "box desc" }, self.description()],
+                    self.title_link(),
+                    self.by_author(),
                     div![
-                        attrs! { At::Class => "box links" },
-                        self.repo_link(),
-                        self.archive_link(),
-                        self.wiki_link(),
+                        attrs! { At::Class => "compact-stars" },
+                        format!("★ {}", thousands(self.0.stars))
                     ],
-                    div![attrs! { At::Class => "box assets" }, self.assets_list()],
-                    div![attrs! { At::Class => "box contents" }, self.contents_list()],
-                    div![attrs! { At::Class => "box stars" }, self.stars_el()],
-                    div![
+                    self.last_commit(),
+                ]
+            ]
+        }
+
+        /// Returns the `Node<Msg>` for the listing, with a snippet of the
+        /// README when the search `query` only matches it, and a checkbox
+        /// selecting it for comparison unless `compared` is `None`.
+        pub fn listing_item(
+            &self,
+            query: &str,
+            expanded: bool,
+            fresh: bool,
+            compared: Option<bool>,
+        ) -> Node<Msg> {
+            self.card(false, expanded, fresh, compared, self.snippet(query))
+        }
+
+        /// Checkbox selecting the mod for comparison.
+        fn compare_toggle(&self, compared: bool) -> Node<Msg> {
+            label![
+                attrs! { At::Class => "compare-toggle", At::Title => "Compare side by side" },
+                input![
+                    attrs! {
+                        At::Type => "checkbox",
+                        At::Checked => compared.as_at_value(),
+                    },
+                    simple_ev(Ev::Change, Msg::ToggleCompare(self.endpoint_query())),
+                ],
+                "compare"
+            ]
+        }
+
+        /// The listing card; the overview shows its description in the header
+        /// instead, and adds the license to the links.
+        fn card(
+            &self,
+            overview: bool,
+            expanded: bool,
+            fresh: bool,
+            compared: Option<bool>,
+            snippet: Node<Msg>,
+        ) -> Node<Msg> {
+            div![
+                attrs! { At::Class => "outside" },
+                if overview {
+                    attrs! {}
+                } else {
+                    attrs! { At::Id => self.anchor(), "role" => "listitem" }
+                },
+                div![
+                    attrs! { At::Class => "wrapper" },
+                    if overview {
+                        style! {}
+                    } else {
+                        style! { St::BorderLeftColor => self.accent() }
+                    },
+                    div![attrs! { At::Class => "box icon" }, self.icon()],
+                    div![
+                        attrs! { At::Class => "box name" },
+                        self.listing_title(fresh)
+                    ],
+                    if !overview {
+                        div![
+                            attrs! { At::Class => "box desc" },
+                            self.description(expanded)
+                        ]
+                    } else {
+                        empty![]
+                    },
+                    div![
+                        attrs! { At::Class => "box links" },
+                        self.repo_link(),
+                        self.archive_link(),
+                        self.wiki_link(),
+                        if overview {
+                            self.license()
+                        } else {
+                            self.permalink()
+                        },
+                        compared.map_or(empty![], |x| self.compare_toggle(x)),
+                    ],
+                    div![attrs! { At::Class => "box assets" }, self.assets_list()],
+                    div![attrs! { At::Class => "box contents" }, self.contents_list()],
+                    div![attrs! { At::Class => "box stars" }, self.stars_el()],
+                    div![
                         attrs! { At::Class => "box min-version" },
                         self.min_game_version()
                     ],
-                ]
+                ],
+                snippet,
             ]
         }
 
-        /// Returns the `Node<Msg>` for the overview/readme page.
-        pub fn overview_item(&self) -> Node<Msg> {
+        /// Returns the `Node<Msg>` for the overview/readme page, showing
+        /// either the readme or the changelog depending on the `tab`, as the
+        /// already rendered `markdown`.
+        pub fn overview_item(
+            &self,
+            tab: OverviewTab,
+            markdown: Vec<Node<Msg>>,
+            copies: u32,
+            copied: &str,
+            dependents: Node<Msg>,
+        ) -> Node<Msg> {
             div! {
                 div![
                     class!["outside"],
@@ -318,15 +1281,283 @@ mod listing {
                         simple_ev(Ev::Click, Msg::Route(Page::Listing)),
                         "back",
                     ],
+                    button![
+                        attrs! { At::Class => "export", At::Title => "Download the data listed for this mod" },
+                        simple_ev(Ev::Click, Msg::ExportJson(self.endpoint_query())),
+                        "export JSON",
+                    ],
                 ],
 
-                self.listing_item(),
+                self.overview_header(),
+
+                self.gallery(),
+
+                self.card(true, true, false, None, empty![]),
+
+                self.last_commit_msg(),
+
+                self.activity(),
+
+                self.unresolved_dependencies(),
+
+                dependents,
+
+                self.install(copies, copied),
+
+                self.dependency(copies, copied),
+
+                self.overview_tabs(tab),
 
                 div![
                     class!["outside"],
                     div! [
                         class!("markdown"),
-                        md!(&self.0.readme)
+                        markdown
+                    ]
+                ]
+            }
+        }
+
+        /// Renders the markdown of the overview `tab`, which is expensive
+        /// enough for it to be cached.
+        pub fn overview_markdown(&self, tab: OverviewTab) -> Vec<Node<Msg>> {
+            let urls = |url: &str, image: bool| {
+                links::absolute(url, &self.0.repo, &self.0.default_branch, image)
+            };
+            match (tab, self.0.changelog.as_ref()) {
+                (OverviewTab::Changelog, Some(changelog)) => markdown::to_nodes(changelog, &urls),
+                _ if self.0.readme_unavailable => self.readme_fallback(
+                    "README unavailable, it couldn't be fetched from the repository.",
+                ),
+                _ if self.0.readme.trim().is_empty() => {
+                    self.readme_fallback("No README was found in the repository.")
+                }
+                _ => markdown::to_nodes(&self.0.readme, &urls),
+            }
+        }
+
+        /// Shown in place of a missing README: the note of why it's missing,
+        /// the description and a link to the repository.
+        fn readme_fallback(&self, note: &str) -> Vec<Node<Msg>> {
+            vec![
+                p![class!["warning"], note],
+                p![self.0.desc],
+                self.repo_link(),
+            ]
+        }
+
+        /// Buttons switching between the readme and the changelog, if
+        /// the mod has one.
+        fn overview_tabs(&self, tab: OverviewTab) -> Node<Msg> {
+            if self.0.changelog.is_none() {
+                return empty![];
+            }
+            let tab_button = |target: OverviewTab, label: &str| {
+                button![
+                    attrs! { At::Class => if tab == target {"active"} else {""}},
+                    simple_ev(Ev::Click, Msg::SetOverviewTab(target)),
+                    label
+                ]
+            };
+            div![
+                class!["outside"],
+                div![
+                    class!["buttons"],
+                    tab_button(OverviewTab::Readme, "readme"),
+                    tab_button(OverviewTab::Changelog, "changelog"),
+                ]
+            ]
+        }
+
+        /// Header of the overview, with the display name, the `mod.json` name
+        /// when it differs, and the short description.
+        fn overview_header(&self) -> Node<Msg> {
+            let title = self
+                .0
+                .display_name
+                .as_deref()
+                .unwrap_or(&self.0.name_markup);
+            let subtitle = if self.0.display_name.is_some()
+                && common::markup::strip(title).trim() != self.0.name
+            {
+                p![class!["subtitle"], &self.0.name]
+            } else {
+                empty![]
+            };
+            div![
+                class!["outside overview-header"],
+                h2![markup::from_str(title)],
+                subtitle,
+                p![
+                    class!["description"],
+                    markup::from_str(self.0.desc_markup.as_deref().unwrap_or(&self.0.desc))
+                ],
+                self.dates(),
+            ]
+        }
+
+        /// Grid of the screenshots found in the README.
+        fn gallery(&self) -> Node<Msg> {
+            if self.0.screenshots.is_empty() {
+                return empty![];
+            }
+            div![
+                class!["outside gallery"],
+                self.0.screenshots.iter().map(|url| a![
+                    attrs! { At::Href => url, At::Target => "_blank" },
+                    img![attrs! {
+                        At::Src => url,
+                        At::Alt => "screenshot",
+                        "loading" => "lazy",
+                        "onerror" => "this.parentNode.style.display = 'none';",
+                    }]
+                ])
+            ]
+        }
+
+        /// Subject of the last commit, along with its age.
+        fn last_commit_msg(&self) -> Node<Msg> {
+            match self.0.last_commit_msg.as_ref() {
+                Some(msg) if !msg.is_empty() => div![
+                    class!["outside"],
+                    div![
+                        class!["commit-msg"],
+                        span![style! { St::Opacity => "60%" }, "last commit: "],
+                        code![msg],
+                        self.last_commit(),
+                    ]
+                ],
+                _ => empty![],
+            }
+        }
+
+        /// Sparkline of the weekly commits of the last year, if GitHub had
+        /// computed them.
+        fn activity(&self) -> Node<Msg> {
+            const WEEK_WIDTH: usize = 2;
+            const HEIGHT: u32 = 20;
+            let weeks = &self.0.activity;
+            let max = weeks.iter().copied().max().unwrap_or(0);
+            if max == 0 {
+                return empty![];
+            }
+            let points: Vec<String> = weeks
+                .iter()
+                .enumerate()
+                .map(|(i, &n)| format!("{},{}", i * WEEK_WIDTH, HEIGHT - n * HEIGHT / max))
+                .collect();
+            let total: u32 = weeks.iter().sum();
+            div![
+                class!["outside"],
+                div![
+                    class!["activity"],
+                    span![style! { St::Opacity => "60%" }, "activity: "],
+                    svg![
+                        attrs! {
+                            At::ViewBox => format!("0 0 {} {}", (weeks.len() - 1) * WEEK_WIDTH, HEIGHT),
+                            At::Width => weeks.len() * WEEK_WIDTH * 2,
+                            At::Height => HEIGHT * 2,
+                            "preserveAspectRatio" => "none",
+                            "role" => "img",
+                            "aria-label" => format!("{} commits in the last year", total),
+                        },
+                        polyline![attrs! { At::Points => points.join(" ") }]
+                    ],
+                    span![format!("{} commits in the last year", thousands(total))],
+                ]
+            ]
+        }
+
+        /// Entry of `mod.json` dependencies which depends on this mod, by the
+        /// name the game refers to it.
+        pub fn dependency_snippet(&self) -> String {
+            format!("\"dependencies\": [\"{}\"]", self.0.mindustry_name())
+        }
+
+        /// The dependency snippet, with a button copying it.
+        fn dependency(&self, copies: u32, copied: &str) -> Node<Msg> {
+            let snippet = self.dependency_snippet();
+            div![
+                class!["outside"],
+                div![
+                    class!["dependency"],
+                    span![style! { St::Opacity => "60%" }, "depend on it: "],
+                    code![&snippet],
+                    copy_button(snippet, "Copy the dependency entry", copies, copied),
+                ]
+            ]
+        }
+
+        /// The repository as imported in game, with a button copying it.
+        ///
+        /// Mindustry registers no url scheme which could open it directly:
+        /// its mod browser installs mods listed on the in-game browser, and
+        /// otherwise "Import From GitHub" takes the repository as
+        /// `owner/name`, exactly as in `self.0.repo`, and downloads the zip
+        /// archive of its default branch.
+        fn install(&self, copies: u32, copied: &str) -> Node<Msg> {
+            let repo = self.0.repo.clone();
+            div![
+                class!["outside"],
+                div![
+                    class!["dependency"],
+                    span![
+                        style! { St::Opacity => "60%" },
+                        attrs! { At::Title => "Mods > Import Mod > Import From GitHub" },
+                        "open in Mindustry: "
+                    ],
+                    code![&repo],
+                    copy_button(
+                        repo,
+                        "Copy the repository to import in game",
+                        copies,
+                        copied
+                    ),
+                ]
+            ]
+        }
+
+        /// Mods of the listing which depend on this one, linking to their
+        /// overviews, and to the listing searching for them.
+        pub fn dependents(&self, data: &[ListingItem]) -> Node<Msg> {
+            let name = self.0.mindustry_name();
+            let dependents: Vec<&ListingItem> =
+                data.iter().filter(|x| x.0.depends_on(&name)).collect();
+            if dependents.is_empty() {
+                return empty![];
+            }
+            div![
+                class!["outside"],
+                div![
+                    class!["dependents"],
+                    span![style! { St::Opacity => "60%" }, "depended on by: "],
+                    dependents.iter().map(|x| button![
+                        simple_ev(Ev::Click, Msg::Route(Page::Overview(x.endpoint_query()))),
+                        &x.0.name
+                    ]),
+                    button![
+                        attrs! { At::Title => format!("Search {}{}", DEPENDS, name) },
+                        simple_ev(Ev::Click, Msg::ListDependents(name.clone())),
+                        "list them"
+                    ],
+                ]
+            ]
+        }
+
+        /// Warning about dependencies which aren't found in the listing.
+        fn unresolved_dependencies(&self) -> Node<Msg> {
+            let unresolved = &self.0.unresolved_dependencies;
+            if unresolved.is_empty() {
+                empty![]
+            } else {
+                div![
+                    class!["outside"],
+                    p![
+                        class!["warning"],
+                        format!(
+                            "Depends on mods not found in the listing: {}",
+                            unresolved.join(", ")
+                        )
                     ]
                 ]
             }
@@ -336,10 +1567,10 @@ mod listing {
 
 /// Color markup rendering layer.
 mod markup {
-    use super::app::Msg;
+    use super::app::{log, Msg};
     use common::{
         color::{Color, Name},
-        markup::Markup,
+        markup::{self, Markup},
     };
     use seed::{prelude::*, Style, *};
 
@@ -363,41 +1594,136 @@ mod markup {
                 .to_style()
         };
         let mut output: Vec<Node<Msg>> = vec![];
-        for x in Markup::from_str(input).unwrap_or(("", vec![])).1 {
+        let (tokens, unparsed) = markup::from_str_lossy(input);
+        if let Some(unparsed) = unparsed {
+            log(&format!("markup parsing failed at: {:?}", unparsed));
+        }
+        for x in tokens {
             use Markup::*;
             match x {
-                HexColor { r, g, b, a } => match a {
-                    Some(a) => colors.push([r, g, b, a].into()),
-                    None => colors.push([r, g, b].into()),
-                },
-                Named(input) => colors.push(input.into()),
+                HexColor { .. } | Named(_) => colors.extend(x.color()),
                 Popped => {
                     colors.pop();
                 }
                 Text(text) => output.push(span![last(&colors), text]),
                 Escaped => output.push(span![last(&colors), "["]),
-                NewLine => output.push(span![last(&colors), "\n"]),
+                NewLine => output.push(br![]),
             }
         }
         output
     }
 }
 
+/// Document head manipulation, for the tab title and link previews of shared urls.
+mod head {
+    use super::listing::ListingItem;
+
+    const TITLE: &str = "Mindustry Mods";
+    const DESCRIPTION: &str = "Listing of Mindustry mods found on GitHub.";
+
+    /// Sets the content of a `<meta>` tag, creating it if it doesn't exist yet.
+    /// Open Graph tags are keyed by `property`, and Twitter tags by `name`.
+    fn set_meta(attr: &str, key: &str, content: &str) {
+        let document = seed::document();
+        let selector = format!("meta[{}='{}']", attr, key);
+        let meta = match document.query_selector(&selector).ok().flatten() {
+            Some(meta) => meta,
+            None => {
+                let head = document.query_selector("head").ok().flatten();
+                let meta = document.create_element("meta").ok();
+                match (head, meta) {
+                    (Some(head), Some(meta)) => {
+                        let _ = meta.set_attribute(attr, key);
+                        let _ = head.append_child(&meta);
+                        meta
+                    }
+                    _ => return,
+                }
+            }
+        };
+        let _ = meta.set_attribute("content", content);
+    }
+
+    fn set_all(title: &str, description: &str, image: Option<&str>) {
+        set_meta("property", "og:title", title);
+        set_meta("property", "og:description", description);
+        set_meta("name", "twitter:card", "summary");
+        set_meta("name", "twitter:title", title);
+        set_meta("name", "twitter:description", description);
+        if let Some(image) = image {
+            set_meta("property", "og:image", image);
+            set_meta("name", "twitter:image", image);
+        }
+    }
+
+    /// Describes a specific mod.
+    pub fn set_mod(item: &ListingItem) {
+        let icon = item.icon_urls().into_iter().next();
+        seed::document().set_title(&format!("{} - {}", item.0.name, TITLE));
+        set_all(&item.0.name, &item.0.desc, icon.as_deref());
+    }
+
+    /// Describes the listing, which is also the fallback when a mod isn't found.
+    pub fn reset() {
+        seed::document().set_title(TITLE);
+        set_all(TITLE, DESCRIPTION, None);
+    }
+}
+
 /// Base model/msg for application.
 pub mod app {
-    use super::{listing::ListingItem, path};
-    use common::MOD_VERSION;
+    use super::{
+        date, head,
+        listing::{author_term, compare, thousands, ListingItem, ANCHOR_PREFIX, AUTHOR, DEPENDS},
+        path, storage,
+    };
+    use common::{category, failure::Failure, game_version, order, stats, status, MOD_VERSION};
     use seed::{prelude::*, *};
+    use serde::{Deserialize, Serialize};
+    use std::{
+        cell::{Cell, RefCell},
+        cmp::Reverse,
+        collections::{HashMap, HashSet},
+        convert::TryFrom,
+        rc::Rc,
+        time::Duration,
+    };
+    use wasm_bindgen::closure::Closure;
 
     /// Package version string.
     const VERSION: &str = env!("CARGO_PKG_VERSION");
 
     #[wasm_bindgen]
     extern "C" {
+        /// Logs to the browser console.
         #[wasm_bindgen(js_namespace = console)]
-        fn log(s: &str);
+        pub fn log(s: &str);
     }
 
+    /// Element id of the search input.
+    const SEARCH_ID: &str = "search";
+
+    /// Local storage key of the recent search queries.
+    const HISTORY_KEY: &str = "history";
+
+    /// Most recent search queries remembered.
+    const HISTORY_LEN: usize = 10;
+
+    /// Items per page offered, besides all of them.
+    const PAGE_SIZES: &[usize] = &[8, 16, 32];
+
+    /// Number of mods listed by the embeddable widget without a `count` param.
+    const EMBED_COUNT: usize = 5;
+
+    /// Most mods listed by the embeddable widget.
+    const EMBED_MAX_COUNT: usize = 50;
+
+    /// Number of mods compared side by side.
+    const COMPARE_MAX: usize = 2;
+
+    /// Mods rendered a frame while the listing grows, at roughly 3ms each.
+    const RENDER_CHUNK: usize = 5;
+
     struct MaxCount(usize);
 
     impl Default for MaxCount {
@@ -423,6 +1749,12 @@ pub mod app {
 
         /// Listing of mod items.
         Listing,
+
+        /// Repositories which failed to be listed, with links to report them.
+        Issues,
+
+        /// The mods selected for comparison, side by side.
+        Compare,
     }
 
     impl Default for Page {
@@ -436,12 +1768,37 @@ pub mod app {
         /// A vector of mod data.
         data: Vec<ListingItem>,
 
-        /// Button sort state of listing.
-        sorting: Sorting,
+        /// Whether `data` has every mod, rather than being empty or only the
+        /// first mods, fetched first to list them sooner.
+        loaded: bool,
+
+        /// Directory the data is fetched from, as in `path::data_dir`.
+        data_dir: String,
+
+        /// User preferences, persisted to local storage.
+        settings: Settings,
+
+        /// Whether the settings panel is expanded.
+        settings_open: bool,
+
+        /// Whether the breakdown of contents and assets is expanded.
+        breakdown_open: bool,
 
         /// Filtering characters entered by user.
         filtering: Option<String>,
 
+        /// Sorting chosen before `Sorting::Relevance`, which it falls back to
+        /// without a search query, and breaks its ties with.
+        fallback_sorting: Sorting,
+
+        /// Recent search queries, most recent first, persisted to local
+        /// storage.
+        history: Vec<String>,
+
+        /// Whether the search input has focus, which shows the recent search
+        /// queries below it while it's empty.
+        search_focused: bool,
+
         /// Active page which should be rendered.
         page: Page,
 
@@ -457,150 +1814,1451 @@ pub mod app {
         /// This also cuts down the number of icon which needs to
         /// be loaded at once.
         max_count: MaxCount,
-    }
 
-    impl Model {
-        /// Returns listing of mods, sorted by the sort state.
-        fn listing(&self) -> Vec<Node<Msg>> {
-            let mut data = self.data.clone();
-            match self.sorting {
-                Sorting::Commit => data.sort_by_key(|x| x.0.date_tt as u32),
-                Sorting::Stars => data.sort_by_key(|x| x.0.stars),
-            }
-            data.reverse();
-            data.iter()
-                .filter(|x| {
-                    self.filtering
-                        .as_ref()
-                        .map_or(true, |f| x.filtering(f.as_str()))
-                })
-                .take(self.max_count.0)
-                .map(|x| x.listing_item())
-                .collect()
-        }
-    }
+        /// Number of mods of the listing rendered so far, which catches up
+        /// with `max_count` by `RENDER_CHUNK` mods a frame, such that growing
+        /// the listing, even to every mod at once, never blocks the main
+        /// thread for much longer than a frame.
+        rendered: usize,
 
-    /// Sorting of listing.
-    #[derive(Debug, Clone, PartialEq)]
-    pub enum Sorting {
-        /// Github stars.
-        Stars,
+        /// Whether the next chunk of the listing is already scheduled.
+        chunk_scheduled: bool,
 
-        /// Commit datetime.
-        Commit,
-    }
+        /// Indices in `data` of the listed mods, sorted and filtered, which
+        /// `update` keeps up to date, such that growing the rendered listing
+        /// doesn't sort and filter every mod again each frame.
+        listing: Vec<usize>,
 
-    impl Default for Sorting {
-        fn default() -> Self {
-            Self::Commit
-        }
-    }
+        /// What `listing` was sorted and filtered by.
+        listing_inputs: Option<ListingInputs>,
 
-    /// Main message type for seed-rs application.
-    #[derive(Debug, Clone)]
-    pub enum Msg {
-        /// On scroll triggered event.
-        Scroll {
-            /// Scroll position
-            scroll: i64,
+        /// Incremented whenever `data` changes, for `listing_inputs`.
+        data_version: usize,
 
-            /// Window height
-            height: i64,
+        /// Whether the listing is grouped under author headers.
+        group_by_author: bool,
 
-            /// Document offset
-            offset: i64,
-        },
+        /// Authors whose group is expanded, when grouping by author.
+        expanded_authors: HashSet<String>,
 
-        /// Fetched mod data for listing.
-        FetchData(fetch::ResponseDataResult<Vec<ListingItem>>),
+        /// Mods whose description is shown in full in the listing, by
+        /// endpoint query, which persist across sorting and filtering.
+        expanded_descriptions: HashSet<String>,
 
-        /// Set sorting order of listing.
-        SetSort(Sorting),
+        /// Game version the listing is restricted to, by the minimum game
+        /// version of mods.
+        game_version: Option<String>,
 
-        /// Filter by (words?) in string for listing.
-        FilterWords(String),
+        /// Number of texts copied to the clipboard on the current page, for
+        /// confirming copies.
+        copies: u32,
 
-        /// Change the route and then change the page.
-        Route(Page),
+        /// Last text copied to the clipboard, whose copy button shows the
+        /// confirmation.
+        copied: String,
 
-        /// Change page without changing the route. (used when page is
-        /// already the URL and pushing a new route would be incorrect)
-        ChangePage(Page),
+        /// Category the listing is restricted to.
+        category: Option<String>,
 
-        /// Scroll event failed, reason untracked, so just disable scroll
-        /// related behavior.
-        ScrollError,
+        /// Mods selected for comparison, by endpoint query, oldest first.
+        compare: Vec<String>,
+
+        /// Maintenance status the listing is restricted to.
+        status: Option<String>,
+
+        /// Section shown on the overview page.
+        overview_tab: OverviewTab,
+
+        /// Number of latest mods listed when the app is embedded as a widget,
+        /// with `?embed=1&count=N`, which hides the header, the search and
+        /// the settings, and opens overviews on the site instead.
+        embed: Option<usize>,
+
+        /// Rendered overview markdown, by endpoint query and tab, since
+        /// rendering it is expensive. Cleared when new data is fetched.
+        markdown_cache: RefCell<HashMap<(String, OverviewTab), Vec<Node<Msg>>>>,
+
+        /// Repositories which failed to be listed, loaded once the issues
+        /// page is first visited.
+        failures: Option<Vec<Failure>>,
+
+        /// When the data was generated, as a UTC timestamp epoch in seconds.
+        generated_at: Option<f64>,
     }
 
-    fn scroll_to_top() {
-        scroll_to_y(0);
+    /// Every mod, along with when they were generated, such that users know
+    /// how current they are, even when they're served from a cache.
+    #[derive(Debug, Clone, Deserialize)]
+    pub struct Data {
+        /// When the data was generated, as a UTC timestamp epoch in seconds.
+        generated_at: f64,
+
+        mods: Vec<ListingItem>,
     }
 
-    fn scroll_to_y(y: i64) {
-        web_sys::window()
-            .unwrap()
-            .scroll_to_with_x_and_y(0.0, y as _);
+    /// Everything the listing is sorted and filtered by, see `Model::filter`.
+    #[derive(Debug, Clone, PartialEq)]
+    struct ListingInputs {
+        data_version: usize,
+        settings: Settings,
+        filtering: Option<String>,
+        fallback_sorting: Sorting,
+        category: Option<String>,
+        status: Option<String>,
+        game_version: Option<String>,
     }
 
-    fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
-        match msg {
-            Msg::Route(Page::Overview(name)) => {
-                let q = format!("mod={}", name);
-                let url = seed::Url::new(vec![""]).search(&q);
-                seed::push_route(url);
-                scroll_to_top();
-                orders
-                    .skip()
-                    .send_msg(Msg::ChangePage(Page::Overview(name)));
-            }
+    /// User preferences, persisted to local storage as a single json blob,
+    /// such that new ones only need a field here.
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(default)]
+    struct Settings {
+        /// Button sort state of listing.
+        sorting: Sorting,
 
-            Msg::Route(Page::Listing) => {
-                let url = seed::Url::new(vec![""]);
-                seed::push_route(url);
-                model.max_count = Default::default();
-                orders.skip().send_msg(Msg::ChangePage(Page::Listing));
-            }
+        /// Layout of listing items.
+        layout: Layout,
 
-            Msg::ChangePage(page) => {
-                model.page = page;
-            }
+        /// Items per page chosen by the user, which replaces growing the
+        /// listing on scroll; `None` keeps the scroll behavior, and
+        /// `usize::MAX` lists every mod.
+        page_size: Option<usize>,
 
-            Msg::Scroll {
-                scroll,
-                height,
-                offset,
-            } => {
-                if (height + scroll) > (offset - 50) {
-                    model.max_count.0 += model.max_count.0;
-                }
-            }
+        /// How recently listed mods must have been updated.
+        freshness: Freshness,
 
-            Msg::FetchData(data) => match data {
-                Ok(x) => model.data = x,
-                Err(e) => {
-                    log("modmeta loading failed");
-                    log(&format!("{:?}", e));
-                }
-            },
+        /// How recently mods must have been updated for the new badge.
+        new_badge: NewBadge,
+
+        /// Whether the listing is restricted to official mods.
+        official_only: bool,
+
+        /// Whether the listing is restricted to JavaScript mods.
+        scripting_only: bool,
+
+        /// Whether the listing is restricted to mods with contents, such as
+        /// blocks or units.
+        has_contents: bool,
+
+        /// Whether the listing is restricted to mods with assets, such as
+        /// sprites or sounds.
+        has_assets: bool,
+
+        /// Whether the listing is restricted to translation mods.
+        translations_only: bool,
+
+        /// Whether mods without a description are left out of the listing.
+        hide_undescribed: bool,
+    }
+
+    impl Settings {
+        /// Local storage key of the settings.
+        const KEY: &'static str = "settings";
+
+        /// Local storage key of the layout, from before every setting was
+        /// stored together.
+        const LAYOUT_KEY: &'static str = "layout";
+
+        /// Loads the stored settings, or the defaults.
+        fn load() -> Self {
+            storage::load(Self::KEY).unwrap_or_else(|| Self {
+                layout: storage::load(Self::LAYOUT_KEY).unwrap_or_default(),
+                ..Default::default()
+            })
+        }
+    }
+
+    /// Section shown on the overview page.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum OverviewTab {
+        /// The README.md of the repository.
+        Readme,
+
+        /// The CHANGELOG.md of the repository.
+        Changelog,
+    }
+
+    impl Default for OverviewTab {
+        fn default() -> Self {
+            Self::Readme
+        }
+    }
+
+    /// Layout of the listing items.
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub enum Layout {
+        /// Spacious cards, with description, assets and contents.
+        Card,
+
+        /// Single line rows.
+        Compact,
+    }
+
+    impl Default for Layout {
+        fn default() -> Self {
+            Self::Card
+        }
+    }
+
+    impl Model {
+        /// Resets the rendered listing to its first page.
+        fn reset_max_count(&mut self) {
+            self.max_count = self
+                .settings
+                .page_size
+                .map_or_else(Default::default, MaxCount);
+            self.rendered = self.rendered.min(RENDER_CHUNK);
+        }
+
+        /// Whether the rendered listing is behind `max_count`.
+        fn rendering(&self) -> bool {
+            self.rendered < self.max_count.0
+        }
+
+        /// Moves a search query to the front of the recent ones, without
+        /// duplicates, and stores them.
+        fn remember(&mut self, query: &str) {
+            let query = query.trim();
+            if query.is_empty() {
+                return;
+            }
+            self.history.retain(|x| x != query);
+            self.history.insert(0, query.to_string());
+            self.history.truncate(HISTORY_LEN);
+            storage::store(HISTORY_KEY, &self.history);
+        }
+
+        /// Finds a mod by its endpoint query.
+        fn find(&self, name: &str) -> Option<&ListingItem> {
+            self.data.iter().find(|x| x.endpoint_query() == name)
+        }
+
+        /// The only mod whose name is the whole search query, ignoring case,
+        /// which enter opens. `None` on partial matches, or when several mods
+        /// share the name.
+        fn exact_match(&self) -> Option<&ListingItem> {
+            let query = self
+                .filtering
+                .as_deref()
+                .unwrap_or("")
+                .trim()
+                .to_lowercase();
+            if query.is_empty() {
+                return None;
+            }
+            let mut matches = self
+                .data
+                .iter()
+                .filter(|x| x.0.name.trim().to_lowercase() == query);
+            match (matches.next(), matches.next()) {
+                (Some(item), None) => Some(item),
+                _ => None,
+            }
+        }
+
+        /// Subtle button opening the `exact_match`, hinting at enter doing so.
+        fn exact_match_hint(&self) -> Node<Msg> {
+            match self.exact_match() {
+                Some(item) => button![
+                    attrs! { At::Class => "exact-match", At::Title => "Press enter to open" },
+                    simple_ev(Ev::Click, Msg::OpenExactMatch),
+                    format!("open {} →", item.0.name),
+                ],
+                None => empty![],
+            }
+        }
+
+        /// Listed mods, sorted by the sort state, and filtered by the filtering
+        /// words, as of the last `update`.
+        fn filtered(&self) -> Vec<&ListingItem> {
+            self.listing.iter().map(|&i| &self.data[i]).collect()
+        }
+
+        fn listing_inputs(&self) -> ListingInputs {
+            ListingInputs {
+                data_version: self.data_version,
+                settings: self.settings.clone(),
+                filtering: self.filtering.clone(),
+                fallback_sorting: self.fallback_sorting.clone(),
+                category: self.category.clone(),
+                status: self.status.clone(),
+                game_version: self.game_version.clone(),
+            }
+        }
+
+        /// Sorts and filters the listing again, if anything it depends on
+        /// changed since.
+        fn update_listing(&mut self) {
+            let inputs = self.listing_inputs();
+            if self.listing_inputs.as_ref() != Some(&inputs) {
+                self.listing = self.filter();
+                self.listing_inputs = Some(inputs);
+            }
+        }
+
+        /// Whether a mod is listed, given the filters and filtering words.
+        fn lists(&self, x: &ListingItem) -> bool {
+            (!self.settings.official_only || x.is_official())
+                && (!self.settings.scripting_only || x.0.is_js_mod)
+                && (!self.settings.has_contents || !x.0.contents.is_empty())
+                && (!self.settings.has_assets || !x.0.assets.is_empty())
+                && (!self.settings.translations_only || x.0.is_translation)
+                && (!self.settings.hide_undescribed || x.0.has_description())
+                && self
+                    .settings
+                    .freshness
+                    .max_age()
+                    .map_or(true, |max_age| x.updated_within(max_age))
+                && self.category.as_ref().map_or(true, |c| &x.0.category == c)
+                && self.status.as_ref().map_or(true, |s| x.status() == s)
+                && self
+                    .game_version
+                    .as_ref()
+                    .map_or(true, |v| x.0.matches_game_version(v))
+                && self
+                    .filtering
+                    .as_ref()
+                    .map_or(true, |f| x.filtering(f.as_str()))
+        }
+
+        /// Indices in `data` of the listed mods, sorted by the sort state.
+        fn filter(&self) -> Vec<usize> {
+            let data = &self.data;
+            let mut indices: Vec<usize> =
+                (0..data.len()).filter(|&i| self.lists(&data[i])).collect();
+            let query = self.filtering.as_deref().unwrap_or("").trim();
+            match self.settings.sorting {
+                Sorting::Relevance => {
+                    // the sort is stable, which keeps the fallback order among ties
+                    self.fallback_sorting.sort(data, &mut indices);
+                    if !query.is_empty() {
+                        let relevance = |&i: &usize| Reverse(order::relevance(&data[i].0, query));
+                        indices.sort_by_cached_key(relevance);
+                    }
+                }
+                ref sorting => sorting.sort(data, &mut indices),
+            }
+            indices
+        }
+
+        /// Returns the overview of a mod, with its markdown from the cache.
+        fn overview(&self, item: &ListingItem) -> Node<Msg> {
+            let tab = self.overview_tab;
+            let markdown = self
+                .markdown_cache
+                .borrow_mut()
+                .entry((item.endpoint_query(), tab))
+                .or_insert_with(|| item.overview_markdown(tab))
+                .clone();
+            let dependents = item.dependents(&self.data);
+            item.overview_item(tab, markdown, self.copies, &self.copied, dependents)
+        }
+
+        /// Returns listing of mods, sorted by the sort state, along with the
+        /// number of mods which aren't rendered yet.
+        fn listing(&self) -> (Vec<Node<Msg>>, usize) {
+            let data = self.filtered();
+            if self.group_by_author {
+                (self.author_groups(&data), 0)
+            } else {
+                let remaining = data.len().saturating_sub(self.max_count.0);
+                let listing = data
+                    .iter()
+                    .take(self.max_count.0.min(self.rendered))
+                    .map(|x| self.item(x))
+                    .collect();
+                (listing, remaining)
+            }
+        }
+
+        /// Dropdown of the recent search queries, below the focused search
+        /// input while it's empty. Its buttons act on mouse down, since a
+        /// click would first blur the input and hide them.
+        fn history(&self) -> Node<Msg> {
+            let empty = self.filtering.as_deref().unwrap_or("").is_empty();
+            if !self.search_focused || !empty || self.history.is_empty() {
+                return empty![];
+            }
+            let pick = |msg: Msg| {
+                mouse_ev(Ev::MouseDown, move |event| {
+                    event.prevent_default();
+                    msg
+                })
+            };
+            div![
+                attrs! { At::Class => "history", "role" => "listbox", "aria-label" => "Recent searches" },
+                self.history.iter().map(|query| button![
+                    attrs! { "role" => "option" },
+                    pick(Msg::FilterWords(query.clone())),
+                    query
+                ]),
+                button![
+                    attrs! { At::Class => "clear-history" },
+                    pick(Msg::ClearHistory),
+                    "clear history"
+                ],
+            ]
+        }
+
+        /// How long ago the data was generated, if it's known.
+        fn generated(&self) -> Node<Msg> {
+            match self.generated_at {
+                Some(at) => {
+                    let ago = Duration::from_secs_f64((date::now_secs() - at).max(0.0));
+                    span![
+                        attrs! { At::Class => "generated" },
+                        format!("data last updated: {}", date::format_ago(ago, *date::LANG))
+                    ]
+                }
+                None => empty![],
+            }
+        }
+
+        /// Small strip of aggregate stats over every mod: the number of mods,
+        /// their total stars, and the number of unique authors.
+        fn stats(&self) -> Node<Msg> {
+            if !self.loaded {
+                return empty![];
+            }
+            let stars = self
+                .data
+                .iter()
+                .fold(0u32, |sum, x| sum.saturating_add(x.0.stars));
+            let authors: HashSet<&str> =
+                self.data.iter().flat_map(|x| x.0.author_names()).collect();
+            let count = |n: usize| thousands(u32::try_from(n).unwrap_or(u32::MAX));
+            div![
+                div![
+                    attrs! { At::Class => "stats" },
+                    span![format!("{} mods", count(self.data.len()))],
+                    span![format!("★ {} stars", thousands(stars))],
+                    span![format!("{} authors", count(authors.len()))],
+                    self.generated(),
+                    button![
+                        attrs! {
+                            At::Class => if self.breakdown_open {"active"} else {""},
+                            "aria-expanded" => self.breakdown_open.to_string(),
+                        },
+                        simple_ev(Ev::Click, Msg::ToggleBreakdown),
+                        "breakdown"
+                    ],
+                ],
+                if self.breakdown_open {
+                    self.breakdown()
+                } else {
+                    empty![]
+                },
+            ]
+        }
+
+        /// Number of mods with each content and asset, as labeled bars
+        /// relative to the most common one.
+        fn breakdown(&self) -> Node<Msg> {
+            let counts = stats::tag_counts(self.data.iter().map(|x| &x.0));
+            let max = counts.first().map_or(1, |x| x.1.max(1));
+            div![
+                attrs! { At::Class => "outside breakdown", "role" => "list" },
+                counts.iter().map(|(tag, n)| div![
+                    attrs! { At::Class => "breakdown-row", "role" => "listitem" },
+                    span![attrs! { At::Class => "breakdown-tag" }, tag],
+                    div![
+                        attrs! { At::Class => "breakdown-bar" },
+                        style! { St::Width => format!("{}%", n * 100 / max) },
+                    ],
+                    span![
+                        attrs! { At::Class => "breakdown-count" },
+                        thousands(u32::try_from(*n).unwrap_or(u32::MAX))
+                    ],
+                ])
+            ]
+        }
+
+        /// Minimum game versions declared by mods, newest first, which are
+        /// the choices of the game version filter.
+        fn game_versions(&self) -> Vec<&str> {
+            let mut versions: Vec<&str> = self
+                .data
+                .iter()
+                .filter_map(|x| x.0.min_game_version.as_deref())
+                .filter(|x| game_version::parse(x).is_some())
+                .collect();
+            versions.sort_by(|a, b| game_version::compare(b, a));
+            versions.dedup_by(|a, b| game_version::compare(a, b) == std::cmp::Ordering::Equal);
+            versions
+        }
+
+        /// Returns the listing `Node<Msg>` of a mod, in the active layout.
+        fn item(&self, item: &ListingItem) -> Node<Msg> {
+            match self.settings.layout {
+                Layout::Card => item.listing_item(
+                    self.filtering.as_deref().unwrap_or(""),
+                    self.expanded_descriptions.contains(&item.endpoint_query()),
+                    self.settings
+                        .new_badge
+                        .within()
+                        .map_or(false, |within| item.0.is_fresh(date::now_secs(), within)),
+                    Some(self.compare.contains(&item.endpoint_query())),
+                ),
+                Layout::Compact => item.compact_item(),
+            }
+        }
+
+        /// Returns the listing partitioned by author, with one collapsible
+        /// header per author. Groups keep the order of their first mod, so
+        /// sorting applies both to the groups and within them. Mods with
+        /// several authors are in the group of each.
+        fn author_groups(&self, data: &[&ListingItem]) -> Vec<Node<Msg>> {
+            let mut index: HashMap<&str, usize> = HashMap::new();
+            let mut groups: Vec<(&str, Vec<&ListingItem>)> = vec![];
+            for &item in data {
+                for author in item.0.author_names() {
+                    match index.get(author) {
+                        Some(&i) => groups[i].1.push(item),
+                        None => {
+                            index.insert(author, groups.len());
+                            groups.push((author, vec![item]));
+                        }
+                    }
+                }
+            }
+
+            groups
+                .iter()
+                .take(self.max_count.0)
+                .map(|(author, items)| {
+                    let expanded = self.expanded_authors.contains(*author);
+                    div![
+                        attrs! { At::Class => "author-group", "role" => "listitem" },
+                        div![
+                            attrs! { At::Class => "outside" },
+                            button![
+                                attrs! { At::Class => "author-header" },
+                                simple_ev(Ev::Click, Msg::ToggleAuthor(author.to_string())),
+                                format!(
+                                    "{} {} ({})",
+                                    if expanded { "-" } else { "+" },
+                                    author,
+                                    items.len()
+                                ),
+                            ],
+                        ],
+                        if expanded {
+                            div![
+                                attrs! { "role" => "list", "aria-label" => format!("Mods by {}", author) },
+                                items.iter().map(|x| self.item(x))
+                            ]
+                        } else {
+                            empty![]
+                        }
+                    ]
+                })
+                .collect()
+        }
+    }
+
+    /// Sorting of listing.
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub enum Sorting {
+        /// Github stars.
+        Stars,
+
+        /// Commit datetime.
+        Commit,
+
+        /// Repository creation datetime, newest first.
+        Created,
+
+        /// Stars decayed by the time since the last commit, see
+        /// `order::trending_score`.
+        Trending,
+
+        /// Matches of the search query, see `order::relevance`.
+        Relevance,
+    }
+
+    impl Default for Sorting {
+        fn default() -> Self {
+            Self::Commit
+        }
+    }
+
+    impl Sorting {
+        /// Name of the sorting in the `sort` url parameter, see `order::ALL`.
+        fn name(&self) -> &'static str {
+            match self {
+                Self::Stars => order::STARS,
+                Self::Commit => order::COMMIT,
+                Self::Created => order::CREATED,
+                Self::Trending => order::TRENDING,
+                Self::Relevance => order::RELEVANCE,
+            }
+        }
+
+        /// Sorting of a name, or the default if it's unknown.
+        fn from_name(name: &str) -> Self {
+            match name {
+                order::STARS => Self::Stars,
+                order::CREATED => Self::Created,
+                order::TRENDING => Self::Trending,
+                order::RELEVANCE => Self::Relevance,
+                _ => Self::Commit,
+            }
+        }
+
+        /// Sorts the `indices` of mods in `data`, by the default sorting for
+        /// `Relevance`, which needs a query, see `Model::filter`.
+        fn sort(&self, data: &[ListingItem], indices: &mut [usize]) {
+            let mods = |&a: &usize, &b: &usize| (&data[a].0, &data[b].0);
+            match self {
+                Self::Stars => indices.sort_by(|a, b| {
+                    let (a, b) = mods(a, b);
+                    order::by_stars(a, b)
+                }),
+                Self::Created => indices.sort_by(|a, b| {
+                    let (a, b) = mods(a, b);
+                    order::by_created(a, b)
+                }),
+                Self::Trending => {
+                    let now = date::now_secs();
+                    indices.sort_by(|a, b| {
+                        let (a, b) = mods(a, b);
+                        order::by_trending(a, b, now)
+                    })
+                }
+                Self::Commit | Self::Relevance => indices.sort_by(|a, b| {
+                    let (a, b) = mods(a, b);
+                    order::by_commit(a, b)
+                }),
+            }
+        }
+    }
+
+    /// How recently mods must have been updated to be listed.
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub enum Freshness {
+        /// Updated within the last week.
+        Week,
+
+        /// Updated within the last month.
+        Month,
+
+        /// Updated within the last year.
+        Year,
+
+        /// Updated at any time.
+        All,
+    }
+
+    impl Default for Freshness {
+        fn default() -> Self {
+            Self::All
+        }
+    }
+
+    impl Freshness {
+        /// Every freshness, in the order of the dropdown.
+        const ALL: [Freshness; 4] = [Self::All, Self::Week, Self::Month, Self::Year];
+
+        /// Maximum age of the last commit, or `None` if any age is fine.
+        fn max_age(self) -> Option<Duration> {
+            const DAY: u64 = 24 * 60 * 60;
+            match self {
+                Self::Week => Some(Duration::from_secs(7 * DAY)),
+                Self::Month => Some(Duration::from_secs(30 * DAY)),
+                Self::Year => Some(Duration::from_secs(365 * DAY)),
+                Self::All => None,
+            }
+        }
+
+        /// Value and label of the dropdown option.
+        fn value(self) -> &'static str {
+            match self {
+                Self::Week => "last week",
+                Self::Month => "last month",
+                Self::Year => "last year",
+                Self::All => "all",
+            }
+        }
+
+        fn from_value(value: &str) -> Self {
+            Self::ALL
+                .iter()
+                .copied()
+                .find(|x| x.value() == value)
+                .unwrap_or_default()
+        }
+    }
+
+    /// How recently mods must have been updated to get the new badge.
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub enum NewBadge {
+        /// Updated within the last day.
+        Day,
+
+        /// Updated within the last week.
+        Week,
+
+        /// Updated within the last month.
+        Month,
+
+        /// No badge.
+        Off,
+    }
+
+    impl Default for NewBadge {
+        fn default() -> Self {
+            Self::Week
+        }
+    }
+
+    impl NewBadge {
+        /// Every threshold, in the order of the dropdown.
+        const ALL: [NewBadge; 4] = [Self::Day, Self::Week, Self::Month, Self::Off];
+
+        /// Maximum age of the last commit, or `None` if there's no badge.
+        fn within(self) -> Option<Duration> {
+            const DAY: u64 = 24 * 60 * 60;
+            match self {
+                Self::Day => Some(Duration::from_secs(DAY)),
+                Self::Week => Some(Duration::from_secs(7 * DAY)),
+                Self::Month => Some(Duration::from_secs(30 * DAY)),
+                Self::Off => None,
+            }
+        }
+
+        /// Value and label of the dropdown option.
+        fn value(self) -> &'static str {
+            match self {
+                Self::Day => "1 day",
+                Self::Week => "7 days",
+                Self::Month => "30 days",
+                Self::Off => "off",
+            }
+        }
+
+        fn from_value(value: &str) -> Self {
+            Self::ALL
+                .iter()
+                .copied()
+                .find(|x| x.value() == value)
+                .unwrap_or_default()
+        }
+    }
+
+    /// Main message type for seed-rs application.
+    #[derive(Debug, Clone)]
+    pub enum Msg {
+        /// On scroll triggered event.
+        Scroll {
+            /// Scroll position
+            scroll: i64,
+
+            /// Window height
+            height: i64,
+
+            /// Document offset
+            offset: i64,
+        },
+
+        /// Fetched mod data for listing.
+        FetchData(fetch::ResponseDataResult<Data>),
+
+        /// Fetched first mods of the data, listed until the rest is fetched.
+        FetchFirstData(fetch::ResponseDataResult<Vec<ListingItem>>),
+
+        /// Fetch repositories which failed to be listed.
+        FetchFailures(fetch::ResponseDataResult<Vec<Failure>>),
+
+        /// Set sorting order of listing.
+        SetSort(Sorting),
+
+        /// Filter by (words?) in string for listing.
+        FilterWords(String),
+
+        /// Remember a search query, once it's committed with enter or by
+        /// leaving the search input.
+        CommitSearch(String),
+
+        /// Open the overview of the mod whose name is the whole search query,
+        /// if there's exactly one.
+        OpenExactMatch,
+
+        /// Show or hide the recent search queries, with the focus of the
+        /// search input.
+        FocusedSearch(bool),
+
+        /// Forget the recent search queries.
+        ClearHistory,
+
+        /// Search the listing for the mods depending on a mod, by name.
+        ListDependents(String),
+
+        /// Search the listing for the mods of an author, by name.
+        ListByAuthor(String),
+
+        /// Select a mod for comparison, by endpoint query, or deselect it.
+        ToggleCompare(String),
+
+        /// Deselect every mod selected for comparison.
+        ClearCompare,
+
+        /// Compare mods, by endpoint query, as in `?compare=a,b`.
+        Compare(Vec<String>),
+
+        /// Toggle grouping the listing by author.
+        ToggleGroupByAuthor,
+
+        /// Expand or collapse the group of an author.
+        ToggleAuthor(String),
+
+        /// Show the full description of a listed mod, or clamp it again.
+        ToggleDescription(String),
+
+        /// Toggle restricting the listing to official mods.
+        ToggleOfficialOnly,
+
+        /// Toggle restricting the listing to JavaScript mods.
+        ToggleScriptingOnly,
+
+        /// Toggle restricting the listing to mods with contents.
+        ToggleHasContents,
+
+        /// Toggle restricting the listing to mods with assets.
+        ToggleHasAssets,
+
+        /// Toggle restricting the listing to translation mods.
+        ToggleTranslationsOnly,
+
+        /// Toggle leaving mods without a description out of the listing.
+        ToggleHideUndescribed,
+
+        /// Restrict the listing to mods updated recently enough.
+        SetFreshness(Freshness),
+
+        /// Set how recently mods must have been updated for the new badge.
+        SetNewBadge(NewBadge),
+
+        /// Restrict the listing to mods compatible with a game version, or
+        /// lift the restriction with an empty string.
+        SetGameVersion(String),
+
+        /// Restrict the listing to a category, or lift the restriction with
+        /// an empty string.
+        SetCategory(String),
+
+        /// Restrict the listing to a maintenance status, or lift the
+        /// restriction with an empty string.
+        SetStatus(String),
+
+        /// Set the layout of listing items.
+        SetLayout(Layout),
+
+        /// Expand or collapse the settings panel.
+        ToggleSettings,
+
+        /// Expand or collapse the breakdown of contents and assets.
+        ToggleBreakdown,
+
+        /// Reset the settings to their defaults.
+        ResetSettings,
+
+        /// Switch the section shown on the overview page.
+        SetOverviewTab(OverviewTab),
+
+        /// Change the route and then change the page.
+        Route(Page),
+
+        /// Change page without changing the route. (used when page is
+        /// already the URL and pushing a new route would be incorrect)
+        ChangePage(Page),
+
+        /// Scroll to the README heading of the url fragment, if any.
+        ScrollToAnchor,
+
+        /// Scroll to the listed mod of the url fragment, if any, once it's
+        /// rendered.
+        ScrollToMod,
+
+        /// Render more of the listing, without relying on scrolling.
+        LoadMore,
+
+        /// Route to the overview of a random mod of the filtered listing.
+        RandomMod,
+
+        /// Render exactly this many items per page, and stop growing the
+        /// listing on scroll.
+        SetMaxCount(usize),
+
+        /// Grow the listing until it fills the viewport, since pages which
+        /// are too short to scroll never trigger `Scroll`.
+        FillViewport,
+
+        /// Scroll event failed, reason untracked, so just disable scroll
+        /// related behavior.
+        ScrollError,
+
+        /// Render the embeddable widget of the latest mods, with their count.
+        Embed(usize),
+
+        /// Render the next chunk of the growing listing.
+        RenderChunk,
+
+        /// Copy a text to the clipboard.
+        CopyText(String),
+
+        /// Download the data of a mod, by endpoint query, as a json file.
+        ExportJson(String),
+
+        /// Move keyboard focus to the search input.
+        FocusSearch,
+
+        /// Event which requires no handling.
+        Ignore,
+    }
+
+    /// Writes a text to the clipboard, with `navigator.clipboard.writeText`,
+    /// which is looked up dynamically since it's an unstable web-sys API.
+    /// Returns whether the browser supports it.
+    fn copy_to_clipboard(text: &str) -> bool {
+        let copy = || {
+            let navigator = web_sys::window()?.navigator();
+            let clipboard = js_sys::Reflect::get(&navigator, &"clipboard".into()).ok()?;
+            let write = js_sys::Reflect::get(&clipboard, &"writeText".into()).ok()?;
+            let write = write.dyn_into::<js_sys::Function>().ok()?;
+            write.call1(&clipboard, &text.into()).ok()
+        };
+        copy().is_some()
+    }
+
+    /// Downloads a text as a file named `name`, through a blob url, which is
+    /// revoked a moment later, once the download has started. Returns
+    /// whether it started.
+    fn download(name: &str, text: &str, mime: &str) -> bool {
+        let start = || {
+            let parts = js_sys::Array::of1(&text.into());
+            let blob = web_sys::Blob::new_with_str_sequence_and_options(
+                &parts,
+                web_sys::BlobPropertyBag::new().type_(mime),
+            )
+            .ok()?;
+            let url = web_sys::Url::create_object_url_with_blob(&blob).ok()?;
+            let link = seed::document()
+                .create_element("a")
+                .ok()?
+                .dyn_into::<web_sys::HtmlAnchorElement>()
+                .ok()?;
+            link.set_href(&url);
+            link.set_download(name);
+            link.click();
+            let revoke = Closure::once_into_js(move || {
+                let _ = web_sys::Url::revoke_object_url(&url);
+            });
+            seed::window()
+                .set_timeout_with_callback_and_timeout_and_arguments_0(
+                    revoke.unchecked_ref(),
+                    1_000,
+                )
+                .ok()
+        };
+        start().is_some()
+    }
+
+    /// Url of the listing, with the sorting unless it's the default, such
+    /// that shared links list the same way.
+    fn listing_url(sorting: &Sorting) -> seed::Url {
+        let url = seed::Url::new(vec![""]);
+        if *sorting == Sorting::default() {
+            url
+        } else {
+            url.search(&format!("sort={}", sorting.name()))
+        }
+    }
+
+    /// Replaces the url of the current history entry, with the same state
+    /// `seed::push_route` gives the entries it pushes.
+    fn replace_route(url: seed::Url) {
+        let mut path = format!("/{}", url.path.join("/"));
+        if let Some(search) = &url.search {
+            path = format!("{}?{}", path, search);
+        }
+        let state = serde_json::to_string(&url).map_or(JsValue::NULL, |x| JsValue::from_str(&x));
+        if seed::history()
+            .replace_state_with_url(&state, "", Some(&path))
+            .is_err()
+        {
+            log("replacing the url failed");
+        }
+    }
+
+    fn scroll_to_top() {
+        scroll_to_y(0);
+    }
+
+    fn scroll_to_y(y: i64) {
+        web_sys::window()
+            .unwrap()
+            .scroll_to_with_x_and_y(0.0, y as _);
+    }
+
+    /// Describes the active page in the document head.
+    fn update_head(model: &Model) {
+        let item = match &model.page {
+            Page::Overview(name) => model.find(name),
+            Page::Listing | Page::Issues | Page::Compare => None,
+        };
+        match item {
+            Some(item) => head::set_mod(item),
+            None => head::reset(),
+        }
+    }
+
+    fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+        let settings = model.settings.clone();
+        update_msg(msg, model, orders);
+        model.update_listing();
+        if model.settings != settings {
+            storage::store(Settings::KEY, &model.settings);
+        }
+        if model.rendering() && !model.chunk_scheduled {
+            model.chunk_scheduled = true;
+            orders.render().after_next_render(|_| Msg::RenderChunk);
+        }
+    }
+
+    fn update_msg(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+        match msg {
+            Msg::Route(Page::Overview(name)) if model.embed.is_some() => {
+                let url = format!("{}?mod={}", path::ROOT, name);
+                let opened = seed::window().open_with_url_and_target(&url, "_blank");
+                if opened.is_err() {
+                    log("opening the overview failed");
+                }
+            }
+
+            Msg::Route(Page::Overview(name)) => {
+                let q = format!("mod={}", name);
+                let url = seed::Url::new(vec![""]).search(&q);
+                seed::push_route(url);
+                scroll_to_top();
+                orders
+                    .skip()
+                    .send_msg(Msg::ChangePage(Page::Overview(name)));
+            }
+
+            Msg::Route(Page::Issues) => {
+                let url = seed::Url::new(vec![""]).search("issues");
+                seed::push_route(url);
+                scroll_to_top();
+                orders.skip().send_msg(Msg::ChangePage(Page::Issues));
+            }
+
+            Msg::Route(Page::Compare) => {
+                let q = format!("compare={}", model.compare.join(","));
+                let url = seed::Url::new(vec![""]).search(&q);
+                seed::push_route(url);
+                scroll_to_top();
+                orders.skip().send_msg(Msg::ChangePage(Page::Compare));
+            }
+
+            Msg::Route(Page::Listing) => {
+                seed::push_route(listing_url(&model.settings.sorting));
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+                orders.skip().send_msg(Msg::ChangePage(Page::Listing));
+            }
+
+            Msg::Embed(count) => {
+                model.embed = Some(count);
+                orders.send_msg(Msg::ChangePage(Page::Listing));
+            }
+
+            Msg::ChangePage(page) => {
+                model.page = page;
+                model.copies = 0;
+                model.overview_tab = Default::default();
+                update_head(model);
+                if let Page::Overview(_) = model.page {
+                    orders.after_next_render(|_| Msg::ScrollToAnchor);
+                }
+                if model.page == Page::Listing && model.loaded {
+                    orders.after_next_render(|_| Msg::ScrollToMod);
+                }
+                if model.page == Page::Issues && model.failures.is_none() {
+                    orders.perform_cmd(fetch_failures(model.data_dir.clone()));
+                }
+            }
+
+            Msg::Scroll {
+                scroll,
+                height,
+                offset,
+            } => {
+                let bottom = (height + scroll) > (offset - 50);
+                if model.settings.page_size.is_none() && bottom && !model.rendering() {
+                    model.max_count.0 += model.max_count.0;
+                }
+            }
+
+            Msg::ScrollToAnchor => {
+                orders.skip();
+                let hash = seed::window().location().hash().unwrap_or_default();
+                let id = hash.trim_start_matches('#');
+                if !id.is_empty() {
+                    if let Some(el) = seed::document().get_element_by_id(id) {
+                        el.scroll_into_view();
+                    }
+                }
+            }
+
+            Msg::ScrollToMod => {
+                orders.skip();
+                let hash = seed::window().location().hash().unwrap_or_default();
+                let id = hash.trim_start_matches('#');
+                let name = match id.strip_prefix(ANCHOR_PREFIX) {
+                    Some(name) => name,
+                    None => return,
+                };
+                if let Some(el) = seed::document().get_element_by_id(id) {
+                    el.scroll_into_view();
+                    return;
+                }
+
+                // render the listing up to the mod, which takes a few frames
+                let filtered = model.filtered();
+                let index = match filtered.iter().position(|x| x.endpoint_query() == name) {
+                    Some(index) => index,
+                    None => return,
+                };
+                if model.group_by_author {
+                    let author = filtered[index].0.author_names()[0].to_string();
+                    if model.expanded_authors.insert(author) {
+                        orders.render().after_next_render(|_| Msg::ScrollToMod);
+                    }
+                } else if model.max_count.0 <= index || model.rendering() {
+                    model.max_count.0 = model.max_count.0.max(index + 1);
+                    orders.render().after_next_render(|_| Msg::ScrollToMod);
+                }
+            }
+
+            Msg::LoadMore => {
+                let page = model.settings.page_size.unwrap_or(MaxCount::default().0);
+                model.max_count.0 = model.max_count.0.saturating_add(page);
+            }
+
+            Msg::RandomMod => {
+                orders.skip();
+                let filtered = model.filtered();
+                if !filtered.is_empty() {
+                    let i = (js_sys::Math::random() * filtered.len() as f64) as usize;
+                    let item = &filtered[i.min(filtered.len() - 1)];
+                    orders.send_msg(Msg::Route(Page::Overview(item.endpoint_query())));
+                }
+            }
+
+            Msg::SetMaxCount(count) => {
+                model.settings.page_size = Some(count);
+                model.reset_max_count();
+            }
+
+            Msg::FillViewport => {
+                let short = web_sys::window()
+                    .and_then(|window| {
+                        let height = window.inner_height().ok()?.as_f64()?.round() as i64;
+                        let offset = window.document()?.body()?.offset_height() as i64;
+                        Some(offset < height + 50)
+                    })
+                    .unwrap_or(false);
+                let remaining = model.max_count.0 < model.listing.len();
+                let scrolling = model.settings.page_size.is_none() && !model.group_by_author;
+                if model.rendering() {
+                    // the viewport is only known to be short once caught up
+                    orders.skip().after_next_render(|_| Msg::FillViewport);
+                } else if short && remaining && scrolling && model.page == Page::Listing {
+                    model.max_count.0 += MaxCount::default().0;
+                    orders.after_next_render(|_| Msg::FillViewport);
+                } else {
+                    orders.skip();
+                }
+            }
+
+            Msg::FetchData(data) => match data {
+                Ok(x) => {
+                    model.data = x.mods;
+                    model.data_version += 1;
+                    model.generated_at = Some(x.generated_at);
+                    model.loaded = true;
+                    model.markdown_cache.borrow_mut().clear();
+                    update_head(model);
+                    orders.after_next_render(|_| Msg::FillViewport);
+                    if let Page::Overview(_) = model.page {
+                        orders.after_next_render(|_| Msg::ScrollToAnchor);
+                    }
+                    if model.page == Page::Listing {
+                        orders.after_next_render(|_| Msg::ScrollToMod);
+                    }
+                }
+                Err(e) => {
+                    log("modmeta loading failed");
+                    log(&format!("{:?}", e));
+                }
+            },
+
+            // ignored once every mod is fetched, in case they came first
+            Msg::FetchFirstData(data) => match data {
+                Ok(x) if !model.loaded => {
+                    model.data = x;
+                    model.data_version += 1;
+                    orders.after_next_render(|_| Msg::FillViewport);
+                }
+                Ok(_) => {
+                    orders.skip();
+                }
+                Err(e) => {
+                    log("first modmeta loading failed");
+                    log(&format!("{:?}", e));
+                }
+            },
+
+            Msg::FetchFailures(data) => match data {
+                Ok(x) => model.failures = Some(x),
+                Err(e) => {
+                    log("failures loading failed");
+                    log(&format!("{:?}", e));
+                }
+            },
 
             Msg::SetSort(sorting) => {
-                model.max_count = Default::default();
-                model.sorting = sorting
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+                if sorting == Sorting::Relevance && model.settings.sorting != Sorting::Relevance {
+                    model.fallback_sorting = model.settings.sorting.clone();
+                }
+                model.settings.sorting = sorting;
+                if model.page == Page::Listing && model.embed.is_none() {
+                    replace_route(listing_url(&model.settings.sorting));
+                }
             }
 
             Msg::FilterWords(words) => {
-                model.max_count = Default::default();
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
                 model.filtering = Some(words);
             }
 
+            Msg::CommitSearch(words) => model.remember(&words),
+
+            Msg::OpenExactMatch => match model.exact_match() {
+                Some(item) => {
+                    let page = Page::Overview(item.endpoint_query());
+                    orders.skip().send_msg(Msg::Route(page));
+                }
+                None => {
+                    orders.skip();
+                }
+            },
+
+            Msg::FocusedSearch(focused) => model.search_focused = focused,
+
+            Msg::ClearHistory => {
+                model.history.clear();
+                storage::store(HISTORY_KEY, &model.history);
+            }
+
+            Msg::ListDependents(name) => {
+                model.filtering = Some(format!("{}{}", DEPENDS, name));
+                orders.skip().send_msg(Msg::Route(Page::Listing));
+            }
+
+            Msg::ListByAuthor(name) => {
+                model.filtering = Some(format!("{}{}", AUTHOR, author_term(&name)));
+                orders.skip().send_msg(Msg::Route(Page::Listing));
+            }
+
+            Msg::ToggleCompare(name) => {
+                if model.compare.contains(&name) {
+                    model.compare.retain(|x| x != &name);
+                } else {
+                    // replaces the oldest selection, once there are enough
+                    model.compare.push(name);
+                    if model.compare.len() > COMPARE_MAX {
+                        model.compare.remove(0);
+                    }
+                }
+            }
+
+            Msg::ClearCompare => model.compare.clear(),
+
+            Msg::Compare(names) => {
+                model.compare = names;
+                model.compare.truncate(COMPARE_MAX);
+                orders.send_msg(Msg::ChangePage(Page::Compare));
+            }
+
+            Msg::ToggleGroupByAuthor => {
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+                model.group_by_author = !model.group_by_author;
+            }
+
+            Msg::SetFreshness(freshness) => {
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+                model.settings.freshness = freshness;
+            }
+
+            Msg::SetNewBadge(new_badge) => {
+                model.settings.new_badge = new_badge;
+            }
+
+            Msg::SetGameVersion(version) => {
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+                model.game_version = Some(version).filter(|x| !x.is_empty());
+            }
+
+            Msg::SetCategory(category) => {
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+                model.category = Some(category).filter(|x| !x.is_empty());
+                // keeps the active filter visible, since chips set it too
+                model.settings_open |= model.category.is_some();
+            }
+
+            Msg::SetStatus(status) => {
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+                model.status = Some(status).filter(|x| !x.is_empty());
+                // keeps the active filter visible, since dots set it too
+                model.settings_open |= model.status.is_some();
+            }
+
+            Msg::ToggleOfficialOnly => {
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+                model.settings.official_only = !model.settings.official_only;
+            }
+
+            Msg::ToggleScriptingOnly => {
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+                model.settings.scripting_only = !model.settings.scripting_only;
+            }
+
+            Msg::ToggleHasContents => {
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+                model.settings.has_contents = !model.settings.has_contents;
+            }
+
+            Msg::ToggleHasAssets => {
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+                model.settings.has_assets = !model.settings.has_assets;
+            }
+
+            Msg::ToggleTranslationsOnly => {
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+                model.settings.translations_only = !model.settings.translations_only;
+            }
+
+            Msg::ToggleHideUndescribed => {
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+                model.settings.hide_undescribed = !model.settings.hide_undescribed;
+            }
+
+            Msg::SetOverviewTab(tab) => {
+                model.overview_tab = tab;
+            }
+
+            Msg::SetLayout(layout) => {
+                model.settings.layout = layout;
+            }
+
+            Msg::ToggleBreakdown => {
+                model.breakdown_open = !model.breakdown_open;
+            }
+
+            Msg::ToggleSettings => {
+                model.settings_open = !model.settings_open;
+            }
+
+            Msg::ResetSettings => {
+                model.settings = Default::default();
+                model.reset_max_count();
+                orders.after_next_render(|_| Msg::FillViewport);
+            }
+
+            Msg::ToggleAuthor(author) => {
+                if !model.expanded_authors.remove(&author) {
+                    model.expanded_authors.insert(author);
+                }
+            }
+
+            Msg::ToggleDescription(name) => {
+                if !model.expanded_descriptions.remove(&name) {
+                    model.expanded_descriptions.insert(name);
+                }
+            }
+
+            Msg::RenderChunk => {
+                model.chunk_scheduled = false;
+                model.rendered = model.rendered.saturating_add(RENDER_CHUNK);
+                // caught up once the whole listing is rendered, since
+                // `max_count` may be more than there is to list
+                if model.rendered >= model.max_count.0.min(model.listing.len()) {
+                    model.rendered = model.max_count.0;
+                }
+            }
+
             Msg::ScrollError => {
                 log("ERROR: scroll error");
                 model.max_count.0 = model.data.len();
             }
+
+            Msg::CopyText(text) => {
+                if copy_to_clipboard(&text) {
+                    model.copies += 1;
+                    model.copied = text;
+                } else {
+                    log("copying to the clipboard failed");
+                }
+            }
+
+            Msg::ExportJson(name) => {
+                orders.skip();
+                let json = model
+                    .find(&name)
+                    .and_then(|item| serde_json::to_string_pretty(&item.0).ok());
+                let exported = json.map_or(false, |json| {
+                    download(&format!("{}.json", name), &json, "application/json")
+                });
+                if !exported {
+                    log("exporting the mod failed");
+                }
+            }
+
+            Msg::FocusSearch => {
+                orders.skip();
+                let search = seed::document()
+                    .get_element_by_id(SEARCH_ID)
+                    .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+                if let Some(search) = search {
+                    let _ = search.focus();
+                }
+            }
+
+            Msg::Ignore => {
+                orders.skip();
+            }
         }
     }
 
     fn view(model: &Model) -> impl View<Msg> {
+        if let Some(count) = model.embed {
+            return embed_view(model, count);
+        }
         div! {
             attrs! { At::Class => "app" },
 
@@ -608,119 +3266,677 @@ pub mod app {
             header![
                 match &model.page {
                     Page::Listing => h1!["Mindustry Mods"],
-                    Page::Overview(_) => a![
+                    Page::Overview(_) | Page::Issues | Page::Compare => a![
                         // attrs! { At::Href => "/" },
                         simple_ev(Ev::Click, Msg::Route(Page::Listing)),
                         h1!["Mindustry Mods"]
                     ]
                 },
+                button![
+                    attrs! {
+                        At::Class => "random-mod",
+                        At::Title => "Overview of a random mod",
+                        "aria-label" => "Random mod",
+                    },
+                    simple_ev(Ev::Click, Msg::RandomMod),
+                    "🎲"
+                ],
+                button![
+                    attrs! { At::Class => "issues-link", At::Title => "Mods which failed to load" },
+                    simple_ev(Ev::Click, Msg::Route(Page::Issues)),
+                    "broken mods"
+                ],
                 a![
                     attrs! { At::Href => "https://github.com/SimonWoodburyForget/mindustry-mods" },
                     img![attrs! {
-                        At::Src => path::GITHUB_MARK,
+                        At::Src => path::GITHUB_MARK.as_str(),
                     }]
                 ]
             ],
 
+            // stats strip (listing only)
+            match &model.page {
+                Page::Listing => model.stats(),
+                Page::Overview(_) | Page::Issues | Page::Compare => empty![],
+            },
+
             // button and search bar section
             // (or nothing if overview mode)
             match &model.page {
                 Page::Listing => div! {
                     attrs! { At::Class => "inputs" },
-                    input![
-                        attrs! {
-                            "placeholder" => "search",
-                            At::Value => &model.filtering.as_deref().unwrap_or(""),
-                        },
-                        input_ev(Ev::Input, Msg::FilterWords)
+                    div![
+                        attrs! { At::Class => "search" },
+                        input![
+                            attrs! {
+                                At::Id => SEARCH_ID,
+                                "placeholder" => "search",
+                                "aria-label" => "Search mods",
+                                At::Title => "Every word must match, or one of those separated by |, as in: turret | cannon",
+                                At::Value => &model.filtering.as_deref().unwrap_or(""),
+                            },
+                            input_ev(Ev::Input, Msg::FilterWords),
+                            input_ev(Ev::Change, Msg::CommitSearch),
+                            keyboard_ev(Ev::KeyDown, |event| match event.key().as_str() {
+                                "Enter" => Msg::OpenExactMatch,
+                                _ => Msg::Ignore,
+                            }),
+                            simple_ev(Ev::Focus, Msg::FocusedSearch(true)),
+                            simple_ev(Ev::Blur, Msg::FocusedSearch(false)),
+                        ],
+                        model.exact_match_hint(),
+                        model.history(),
                     ],
                     div! {
                         attrs! { At::Class => "buttons" },
                         p!["Order by : "],
                         button![
-                            attrs! { At::Class => if model.sorting == Sorting::Stars {"active"} else {""}},
+                            attrs! {
+                                At::Class => if model.settings.sorting == Sorting::Stars {"active"} else {""},
+                                "aria-pressed" => (model.settings.sorting == Sorting::Stars).to_string(),
+                            },
                             simple_ev(Ev::Click, Msg::SetSort(Sorting::Stars)),
                             "stars"
                         ],
                         button![
-                            attrs! { At::Class => if model.sorting == Sorting::Commit {"active"} else {""}},
+                            attrs! {
+                                At::Class => if model.settings.sorting == Sorting::Commit {"active"} else {""},
+                                "aria-pressed" => (model.settings.sorting == Sorting::Commit).to_string(),
+                            },
                             simple_ev(Ev::Click, Msg::SetSort(Sorting::Commit)),
                             "commit"
                         ],
+                        button![
+                            attrs! {
+                                At::Class => if model.settings.sorting == Sorting::Created {"active"} else {""},
+                                "aria-pressed" => (model.settings.sorting == Sorting::Created).to_string(),
+                                At::Title => "Newest repositories first",
+                            },
+                            simple_ev(Ev::Click, Msg::SetSort(Sorting::Created)),
+                            "newest"
+                        ],
+                        button![
+                            attrs! {
+                                At::Class => if model.settings.sorting == Sorting::Trending {"active"} else {""},
+                                "aria-pressed" => (model.settings.sorting == Sorting::Trending).to_string(),
+                                At::Title => format!("Stars, halved every {} days since the last commit", order::TRENDING_HALF_LIFE),
+                            },
+                            simple_ev(Ev::Click, Msg::SetSort(Sorting::Trending)),
+                            "trending"
+                        ],
+                        button![
+                            attrs! {
+                                At::Class => if model.settings.sorting == Sorting::Relevance {"active"} else {""},
+                                "aria-pressed" => (model.settings.sorting == Sorting::Relevance).to_string(),
+                                At::Title => "Best matches of the search first, by name, then author, description and README",
+                            },
+                            simple_ev(Ev::Click, Msg::SetSort(Sorting::Relevance)),
+                            "relevance"
+                        ],
+                        button![
+                            attrs! {
+                                At::Class => if model.group_by_author {"active"} else {""},
+                                "aria-pressed" => model.group_by_author.to_string(),
+                            },
+                            simple_ev(Ev::Click, Msg::ToggleGroupByAuthor),
+                            "author"
+                        ],
+                        button![
+                            attrs! {
+                                At::Class => if model.settings_open {"active"} else {""},
+                                "aria-expanded" => model.settings_open.to_string(),
+                            },
+                            simple_ev(Ev::Click, Msg::ToggleSettings),
+                            "settings"
+                        ],
                     }
                 },
-                Page::Overview(_) => div![],
+                Page::Overview(_) | Page::Issues | Page::Compare => div![],
+            },
+
+            // settings panel, when expanded
+            match &model.page {
+                Page::Listing if model.settings_open => settings_panel(model),
+                _ => empty![],
+            },
+
+            // mods selected for comparison
+            match &model.page {
+                Page::Listing if !model.compare.is_empty() => compare_bar(model),
+                _ => empty![],
             },
 
             // listing or overview section
             match &model.page {
-                Page::Overview(ref value) => match &model.data.iter()
-                    .find(|x| x.endpoint_query().as_str() == value.as_str()) {
-                        Some(item) => item.overview_item(),
-                        None => div! {
-                            attrs! { At::Class => "listing-container" },
-                            model.listing(),
-                        }
+                Page::Overview(ref value) => match model.find(value) {
+                        Some(item) => model.overview(item),
+                        None => listing_container(model),
                     }
 
-                Page::Listing => div! {
-                    attrs! { At::Class => "listing-container" },
-                    model.listing(),
-                }
+                Page::Listing => listing_container(model),
+
+                Page::Issues => issues_container(model),
+
+                Page::Compare => compare_container(model),
             }
         }
     }
 
-    async fn fetch_data() -> Result<Msg, Msg> {
-        Request::new(crate::path::DATA.as_str())
+    /// The embeddable widget, listing the `count` latest mods without any of
+    /// the settings, which are the preferences of whoever visits the site.
+    fn embed_view(model: &Model, count: usize) -> Node<Msg> {
+        let mut data: Vec<&ListingItem> = model.data.iter().collect();
+        data.sort_by(|a, b| order::by_commit(&a.0, &b.0));
+        div![
+            attrs! { At::Class => "app embed" },
+            div![
+                attrs! { "role" => "list", "aria-label" => "Latest mods" },
+                data.into_iter().take(count).map(|item| item.listing_item(
+                    "",
+                    model.expanded_descriptions.contains(&item.endpoint_query()),
+                    false,
+                    None
+                ))
+            ],
+            div![
+                attrs! { At::Class => "outside load-more" },
+                a![
+                    attrs! { At::Href => format!("{}.", path::ROOT), At::Target => "_blank" },
+                    "more on Mindustry Mods"
+                ]
+            ]
+        ]
+    }
+
+    /// Panel of the less used toggles and filters, and of the button resetting
+    /// the settings to their defaults.
+    fn settings_panel(model: &Model) -> Node<Msg> {
+        div![
+            attrs! { At::Class => "buttons settings" },
+            button![
+                attrs! {
+                    At::Class => if model.settings.official_only {"active"} else {""},
+                    "aria-pressed" => model.settings.official_only.to_string(),
+                },
+                simple_ev(Ev::Click, Msg::ToggleOfficialOnly),
+                "official"
+            ],
+            button![
+                attrs! {
+                    At::Class => if model.settings.scripting_only {"active"} else {""},
+                    "aria-pressed" => model.settings.scripting_only.to_string(),
+                },
+                simple_ev(Ev::Click, Msg::ToggleScriptingOnly),
+                "scripting"
+            ],
+            button![
+                attrs! {
+                    At::Class => if model.settings.has_contents {"active"} else {""},
+                    "aria-pressed" => model.settings.has_contents.to_string(),
+                },
+                simple_ev(Ev::Click, Msg::ToggleHasContents),
+                "has contents"
+            ],
+            button![
+                attrs! {
+                    At::Class => if model.settings.has_assets {"active"} else {""},
+                    "aria-pressed" => model.settings.has_assets.to_string(),
+                },
+                simple_ev(Ev::Click, Msg::ToggleHasAssets),
+                "has assets"
+            ],
+            button![
+                attrs! {
+                    At::Class => if model.settings.translations_only {"active"} else {""},
+                    "aria-pressed" => model.settings.translations_only.to_string(),
+                },
+                simple_ev(Ev::Click, Msg::ToggleTranslationsOnly),
+                "translations"
+            ],
+            button![
+                attrs! {
+                    At::Class => if model.settings.hide_undescribed {"active"} else {""},
+                    "aria-pressed" => model.settings.hide_undescribed.to_string(),
+                },
+                simple_ev(Ev::Click, Msg::ToggleHideUndescribed),
+                "hide undescribed"
+            ],
+            p!["Updated : "],
+            select![
+                input_ev(Ev::Change, |x| Msg::SetFreshness(Freshness::from_value(&x))),
+                Freshness::ALL.iter().map(|x| option![
+                    attrs! {
+                        At::Value => x.value(),
+                        At::Selected => (model.settings.freshness == *x).as_at_value(),
+                    },
+                    x.value()
+                ]),
+            ],
+            p!["New badge : "],
+            select![
+                input_ev(Ev::Change, |x| Msg::SetNewBadge(NewBadge::from_value(&x))),
+                NewBadge::ALL.iter().map(|x| option![
+                    attrs! {
+                        At::Value => x.value(),
+                        At::Selected => (model.settings.new_badge == *x).as_at_value(),
+                    },
+                    x.value()
+                ]),
+            ],
+            p!["Game version : "],
+            select![
+                input_ev(Ev::Change, Msg::SetGameVersion),
+                option![attrs! { At::Value => "" }, "any"],
+                model.game_versions().into_iter().map(|v| option![
+                    attrs! {
+                        At::Value => v,
+                        At::Selected => (model.game_version.as_deref() == Some(v)).as_at_value(),
+                    },
+                    v
+                ]),
+            ],
+            p!["Category : "],
+            select![
+                input_ev(Ev::Change, Msg::SetCategory),
+                option![attrs! { At::Value => "" }, "any"],
+                category::ALL.iter().map(|&c| option![
+                    attrs! {
+                        At::Value => c,
+                        At::Selected => (model.category.as_deref() == Some(c)).as_at_value(),
+                    },
+                    c
+                ]),
+            ],
+            p![attrs! { At::Title => status::thresholds() }, "Status : "],
+            select![
+                input_ev(Ev::Change, Msg::SetStatus),
+                option![attrs! { At::Value => "" }, "any"],
+                status::ALL.iter().map(|&s| option![
+                    attrs! {
+                        At::Value => s,
+                        At::Selected => (model.status.as_deref() == Some(s)).as_at_value(),
+                    },
+                    s
+                ]),
+            ],
+            p!["Layout : "],
+            button![
+                attrs! {
+                    At::Class => if model.settings.layout == Layout::Card {"active"} else {""},
+                    "aria-pressed" => (model.settings.layout == Layout::Card).to_string(),
+                },
+                simple_ev(Ev::Click, Msg::SetLayout(Layout::Card)),
+                "cards"
+            ],
+            button![
+                attrs! {
+                    At::Class => if model.settings.layout == Layout::Compact {"active"} else {""},
+                    "aria-pressed" => (model.settings.layout == Layout::Compact).to_string(),
+                },
+                simple_ev(Ev::Click, Msg::SetLayout(Layout::Compact)),
+                "compact"
+            ],
+            p!["Per page : "],
+            select![
+                input_ev(Ev::Change, |x| match x.parse() {
+                    Ok(count) => Msg::SetMaxCount(count),
+                    Err(_) => Msg::SetMaxCount(usize::MAX),
+                }),
+                option![
+                    attrs! {
+                        At::Value => "",
+                        At::Disabled => true.as_at_value(),
+                        At::Selected => model.settings.page_size.is_none().as_at_value(),
+                    },
+                    "scroll"
+                ],
+                PAGE_SIZES.iter().map(|&n| option![
+                    attrs! {
+                        At::Value => n,
+                        At::Selected => (model.settings.page_size == Some(n)).as_at_value(),
+                    },
+                    n.to_string()
+                ]),
+                option![
+                    attrs! {
+                        At::Value => "all",
+                        At::Selected => (model.settings.page_size == Some(usize::MAX)).as_at_value(),
+                    },
+                    "all"
+                ],
+            ],
+            button![
+                attrs! { At::Class => "reset" },
+                simple_ev(Ev::Click, Msg::ResetSettings),
+                "reset to defaults"
+            ],
+        ]
+    }
+
+    /// Repositories which failed to be listed, each with a link to report it.
+    fn issues_container(model: &Model) -> Node<Msg> {
+        let failures = match model.failures.as_ref() {
+            Some(x) => x,
+            None => return div![attrs! { At::Class => "outside" }, p!["loading..."]],
+        };
+        div![
+            attrs! { At::Class => "listing-container" },
+            div![
+                attrs! { At::Class => "outside" },
+                h2!["Broken mods"],
+                p![if failures.is_empty() {
+                    "Every mod of the listing loaded fine."
+                } else {
+                    "These repositories couldn't be fetched or parsed, and are missing from the listing."
+                }],
+            ],
+            failures.iter().map(|x| div![
+                attrs! { At::Class => "outside failure" },
+                a![
+                    attrs! { At::Href => format!("https://github.com/{}", x.repo) },
+                    x.repo.as_str()
+                ],
+                p![class!["warning"], x.reason.as_str()],
+                a![attrs! { At::Href => x.issue_link() }, "report"],
+            ]),
+        ]
+    }
+
+    /// Strip naming the mods selected for comparison, to compare them once
+    /// there are enough of them.
+    fn compare_bar(model: &Model) -> Node<Msg> {
+        let names = model.compare.iter().map(|x| {
+            model
+                .find(x)
+                .map_or(x.as_str(), |item| item.0.name.as_str())
+        });
+        div![
+            attrs! { At::Class => "outside compare-bar" },
+            span![format!(
+                "comparing: {}",
+                names.collect::<Vec<_>>().join(", ")
+            )],
+            if model.compare.len() == COMPARE_MAX {
+                button![simple_ev(Ev::Click, Msg::Route(Page::Compare)), "compare"]
+            } else {
+                span![
+                    style! { St::Opacity => "60%" },
+                    format!("select {} more", COMPARE_MAX - model.compare.len())
+                ]
+            },
+            button![simple_ev(Ev::Click, Msg::ClearCompare), "clear"],
+        ]
+    }
+
+    /// Comparison section, of the selected mods which are listed.
+    fn compare_container(model: &Model) -> Node<Msg> {
+        let items: Vec<&ListingItem> = model.compare.iter().filter_map(|x| model.find(x)).collect();
+        div![
+            attrs! { At::Class => "listing-container" },
+            div![
+                class!["outside"],
+                button![
+                    style! { St::Background => "#282828" },
+                    simple_ev(Ev::Click, Msg::Route(Page::Listing)),
+                    "back",
+                ],
+            ],
+            div![
+                class!["outside"],
+                if items.is_empty() {
+                    p![if !model.loaded {
+                        "loading..."
+                    } else {
+                        "None of the mods to compare are listed."
+                    }]
+                } else {
+                    compare(&items)
+                }
+            ],
+        ]
+    }
+
+    /// Mods hand-picked by the maintainers, above the sorted listing.
+    fn featured_section(model: &Model) -> Node<Msg> {
+        let featured: Vec<&ListingItem> = model.data.iter().filter(|x| x.0.featured).collect();
+        if featured.is_empty() {
+            return empty![];
+        }
+        div![
+            attrs! { At::Class => "outside featured" },
+            h2!["Featured"],
+            div![
+                attrs! { At::Class => "featured-tiles", "role" => "list", "aria-label" => "Featured mods" },
+                featured.iter().map(|x| x.featured_tile())
+            ],
+        ]
+    }
+
+    /// Listing section, or a message explaining why nothing matched.
+    fn listing_container(model: &Model) -> Node<Msg> {
+        let (listing, remaining) = model.listing();
+        let query = model.filtering.as_deref().unwrap_or("");
+        if listing.is_empty() && model.loaded && !query.is_empty() {
+            div![
+                attrs! { At::Class => "listing-container" },
+                div![
+                    attrs! { At::Class => "outside no-results" },
+                    p![format!("No mods match '{}'", query)],
+                    button![
+                        simple_ev(Ev::Click, Msg::FilterWords(String::new())),
+                        "clear search"
+                    ],
+                ]
+            ]
+        } else {
+            div![
+                attrs! { At::Class => "listing-container" },
+                if query.is_empty() && model.page == Page::Listing {
+                    featured_section(model)
+                } else {
+                    empty![]
+                },
+                div![attrs! { "role" => "list", "aria-label" => "Mods" }, listing],
+                if remaining > 0 {
+                    div![
+                        attrs! { At::Class => "outside load-more" },
+                        button![
+                            simple_ev(Ev::Click, Msg::LoadMore),
+                            format!("load more ({} remaining)", remaining)
+                        ]
+                    ]
+                } else {
+                    empty![]
+                }
+            ]
+        }
+    }
+
+    async fn fetch_failures(dir: String) -> Result<Msg, Msg> {
+        Request::new(crate::path::failures(&dir))
+            .method(Method::Get)
+            .fetch_json_data(Msg::FetchFailures)
+            .await
+    }
+
+    /// Fetches the data, which the `web` server compresses when the browser
+    /// accepts it, such that the one url works with any static file server.
+    async fn fetch_data(dir: String) -> Result<Msg, Msg> {
+        Request::new(crate::path::data(&dir))
             .method(Method::Get)
             .fetch_json_data(Msg::FetchData)
             .await
     }
 
-    /// Initialize data.
-    fn after_mount(_: Url, orders: &mut impl Orders<Msg>) -> AfterMount<Model> {
-        orders.perform_cmd(fetch_data());
-        AfterMount::default()
+    async fn fetch_first_data(dir: String) -> Result<Msg, Msg> {
+        Request::new(crate::path::data_first(&dir))
+            .method(Method::Get)
+            .fetch_json_data(Msg::FetchFirstData)
+            .await
+    }
+
+    /// Value of a parameter of a url query string, as `b` of `a=b&c=d`.
+    fn url_param(query: &str, name: &str) -> Option<String> {
+        query.split('&').find_map(|pairs| {
+            let mut it = pairs.split('=');
+            let key = it.next().filter(|&k| k == name);
+            let value = it.next().map(|x| x.to_string());
+            key.and(value)
+        })
+    }
+
+    /// Initialize data, and the sorting: the one of the url takes precedence
+    /// over the stored one, see `order::preferred`.
+    fn after_mount(url: Url, orders: &mut impl Orders<Msg>) -> AfterMount<Model> {
+        let data_dir = path::data_dir();
+        // both at once, such that the first mods are listed while the rest
+        // is still downloading
+        orders.perform_cmd(fetch_first_data(data_dir.clone()));
+        orders.perform_cmd(fetch_data(data_dir.clone()));
+        listen_scroll(orders);
+        let mut settings = Settings::load();
+        let sort = url_param(url.search.as_deref().unwrap_or(""), "sort");
+        let sorting = order::preferred(sort.as_deref(), Some(settings.sorting.name()));
+        settings.sorting = Sorting::from_name(sorting);
+        AfterMount::new(Model {
+            data_dir,
+            settings,
+            history: storage::load(HISTORY_KEY).unwrap_or_default(),
+            ..Default::default()
+        })
     }
 
     /// Parse query and change the page to overview if there's a mod param, or
-    /// just to to listing otherwise.
+    /// if it's a pre-rendered `m/{endpoint}.html` page, to the comparison if
+    /// there's a `compare` param, to the embeddable widget if there's an
+    /// `embed=1` param, or just to to listing otherwise.
     fn routes(url: Url) -> Option<Msg> {
-        let find_mod = |query: String| {
-            query.split("&").find_map(|pairs| {
-                let mut it = pairs.split("=");
-                let key = it.next().filter(|&k| k == "mod");
-                let value = it.next().map(|x| x.to_string());
-                key.and(value)
-            })
-        };
+        if let [dir, file] = url.path.as_slice() {
+            if dir == "m" && file.ends_with(".html") {
+                let name = file.trim_end_matches(".html").to_string();
+                return Some(Msg::ChangePage(Page::Overview(name)));
+            }
+        }
+
+        let query = url.search.unwrap_or_default();
+        let param = |name: &str| url_param(&query, name);
 
-        url.search
-            .and_then(find_mod)
+        if query == "issues" {
+            return Some(Msg::ChangePage(Page::Issues));
+        }
+
+        if let Some(names) = param("compare") {
+            let names = names.split(',').filter(|x| !x.is_empty()).map(String::from);
+            return Some(Msg::Compare(names.collect()));
+        }
+
+        if param("embed").as_deref() == Some("1") {
+            let count = param("count")
+                .and_then(|x| x.parse().ok())
+                .unwrap_or(EMBED_COUNT);
+            return Some(Msg::Embed(count.min(EMBED_MAX_COUNT)));
+        }
+
+        param("mod")
             .map(|name| Some(Msg::ChangePage(Page::Overview(name))))
             .unwrap_or(Some(Msg::ChangePage(Page::Listing)))
     }
 
-    fn events(_model: &Model) -> Vec<EventHandler<Msg>> {
-        let some_window = web_sys::window().and_then(|window| {
-            let height = window.inner_height().ok()?.as_f64()?.round() as i64;
-            Some((window, height))
-        });
+    /// Returns whether keyboard input is currently going to a form field.
+    fn is_typing() -> bool {
+        seed::document()
+            .active_element()
+            .map_or(false, |el| match el.tag_name().as_str() {
+                "INPUT" | "TEXTAREA" | "SELECT" => true,
+                _ => false,
+            })
+    }
+
+    /// Height of the window, in pixels.
+    fn window_height(window: &web_sys::Window) -> Option<i64> {
+        Some(window.inner_height().ok()?.as_f64()?.round() as i64)
+    }
+
+    /// Scroll position against the document, with the window `height`.
+    fn scroll_msg(window: &web_sys::Window, height: Option<i64>) -> Msg {
+        let scroll = || {
+            let offset = window.document()?.body()?.offset_height() as i64;
+            let scroll = window.scroll_y().ok()?.round() as i64;
+            Some(Msg::Scroll {
+                scroll,
+                offset,
+                height: height?,
+            })
+        };
+        scroll().unwrap_or(Msg::ScrollError)
+    }
+
+    /// Listens to scrolling outside of `events`, since window events send a
+    /// message for every scroll tick, each of which runs `update` and then
+    /// `events` again. Instead, this sends `Msg::Scroll` at most once an
+    /// animation frame, and only measures the window height on resize.
+    fn listen_scroll(orders: &mut impl Orders<Msg>) {
+        let window = seed::window();
+        let app = orders.clone_app();
+        let msg_mapper = Rc::new(orders.msg_mapper());
+        let height = Rc::new(Cell::new(window_height(&window)));
+        let pending = Rc::new(Cell::new(false));
+
+        let on_resize = {
+            let (window, height) = (window.clone(), height.clone());
+            Closure::wrap(Box::new(move || height.set(window_height(&window))) as Box<dyn FnMut()>)
+        };
 
-        vec![ev(Ev::Scroll, |_| {
-            some_window
-                .and_then(|(window, height)| {
-                    let offset = window.document()?.body()?.offset_height() as i64;
-                    let scroll = window.scroll_y().ok()?.round() as i64;
-                    Some(Msg::Scroll {
-                        scroll,
-                        offset,
-                        height,
+        let on_scroll = {
+            let window = window.clone();
+            Closure::wrap(Box::new(move || {
+                if pending.replace(true) {
+                    return;
+                }
+                let frame = {
+                    let (window, height, pending) =
+                        (window.clone(), height.clone(), pending.clone());
+                    let (app, msg_mapper) = (app.clone(), msg_mapper.clone());
+                    Closure::once_into_js(move || {
+                        pending.set(false);
+                        app.update(msg_mapper(scroll_msg(&window, height.get())));
                     })
-                })
-                .unwrap_or(Msg::ScrollError)
-        })]
+                };
+                if window
+                    .request_animation_frame(frame.unchecked_ref())
+                    .is_err()
+                {
+                    log("requesting an animation frame failed");
+                }
+            }) as Box<dyn FnMut()>)
+        };
+
+        for (event, listener) in vec![("resize", on_resize), ("scroll", on_scroll)] {
+            let added =
+                window.add_event_listener_with_callback(event, listener.as_ref().unchecked_ref());
+            if added.is_err() {
+                log(&format!("listening to {} failed", event));
+            }
+            // listens for as long as the page is open
+            listener.forget();
+        }
+    }
+
+    fn events(model: &Model) -> Vec<EventHandler<Msg>> {
+        let page = model.page.clone();
+        let keydown = keyboard_ev(Ev::KeyDown, move |event| match event.key().as_str() {
+            "/" if page == Page::Listing && !is_typing() => {
+                event.prevent_default();
+                Msg::FocusSearch
+            }
+            // same as the back button of the overview
+            "Escape" if matches!(page, Page::Overview(_)) && !is_typing() => {
+                Msg::Route(Page::Listing)
+            }
+            _ => Msg::Ignore,
+        });
+
+        vec![keydown]
     }
 
     /// Entry point of app.